@@ -2732,12 +2732,31 @@ fn generate_idl(
 ) -> Result<Idl> {
     check_idl_build_feature()?;
 
-    anchor_lang_idl::build::IdlBuilder::new()
+    let idl = anchor_lang_idl::build::IdlBuilder::new()
         .resolution(cfg.features.resolution)
         .skip_lint(cfg.features.skip_lint || skip_lint)
         .no_docs(no_docs)
         .cargo_args(cargo_args.into())
-        .build()
+        .build()?;
+
+    // Inconsistencies such as unresolved type references make the IDL unusable for clients
+    let errors = idl.validate();
+    if !errors.is_empty() {
+        let errors = errors
+            .iter()
+            .map(|err| format!("\n- {err}"))
+            .collect::<String>();
+        bail!("Inconsistent IDL:{errors}");
+    }
+
+    for ix in idl.instructions.iter().filter(|ix| ix.deprecated) {
+        match &ix.deprecation_note {
+            Some(note) => eprintln!("Warning: Instruction `{}` is deprecated: {note}", ix.name),
+            None => eprintln!("Warning: Instruction `{}` is deprecated", ix.name),
+        }
+    }
+
+    Ok(idl)
 }
 
 fn idl_fetch(
@@ -3032,9 +3051,7 @@ fn account(
 
     let data = create_client(cluster.url()).get_account_data(&address)?;
     let disc_len = idl
-        .accounts
-        .iter()
-        .find(|acc| acc.name == account_type_name)
+        .find_account(account_type_name)
         .map(|acc| acc.discriminator.len())
         .ok_or_else(|| anyhow!("Account `{account_type_name}` not found in IDL"))?;
     let mut data_view = &data[disc_len..];
@@ -3057,11 +3074,9 @@ fn deserialize_idl_defined_type_to_json(
     data: &mut &[u8],
 ) -> Result<JsonValue, anyhow::Error> {
     let defined_type = &idl
-        .accounts
-        .iter()
-        .find(|acc| acc.name == defined_type_name)
-        .and_then(|acc| idl.types.iter().find(|ty| ty.name == acc.name))
-        .or_else(|| idl.types.iter().find(|ty| ty.name == defined_type_name))
+        .find_account(defined_type_name)
+        .and_then(|acc| idl.find_type(&acc.name))
+        .or_else(|| idl.find_type(defined_type_name))
         .ok_or_else(|| anyhow!("Type `{}` not found in IDL.", defined_type_name))?
         .ty;
 
@@ -5459,6 +5474,9 @@ mod tests {
                     ty: IdlType::U8,
                 }],
                 returns: None,
+                compute_units: None,
+                deprecated: false,
+                deprecation_note: None,
             }],
             accounts: vec![anchor_lang_idl::types::IdlAccount {
                 name: "source_account".to_string(),
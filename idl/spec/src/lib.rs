@@ -1,9 +1,13 @@
 use {
     anyhow::anyhow,
     serde::{Deserialize, Serialize},
-    std::str::FromStr,
+    std::{collections::HashMap, str::FromStr},
 };
 
+mod validate;
+
+pub use validate::IdlValidationError;
+
 pub const IDL_SPEC: &str = env!("CARGO_PKG_VERSION");
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -25,6 +29,43 @@ pub struct Idl {
     pub constants: Vec<IdlConst>,
 }
 
+impl Idl {
+    /// Find an instruction by name.
+    pub fn find_instruction(&self, name: &str) -> Option<&IdlInstruction> {
+        self.instructions.iter().find(|ix| ix.name == name)
+    }
+
+    /// Find an account by name.
+    pub fn find_account(&self, name: &str) -> Option<&IdlAccount> {
+        self.accounts.iter().find(|acc| acc.name == name)
+    }
+
+    /// Find an error by name.
+    pub fn find_error(&self, name: &str) -> Option<&IdlErrorCode> {
+        self.errors.iter().find(|err| err.name == name)
+    }
+
+    /// Find a type definition by name.
+    pub fn find_type(&self, name: &str) -> Option<&IdlTypeDef> {
+        self.types.iter().find(|ty| ty.name == name)
+    }
+
+    /// Find a constant by name.
+    pub fn find_constant(&self, name: &str) -> Option<&IdlConst> {
+        self.constants.iter().find(|c| c.name == name)
+    }
+
+    /// Build a map of the constants by name, useful when doing many lookups.
+    ///
+    /// The map is computed on each call, so it should be reused rather than rebuilt per lookup.
+    pub fn constants_map(&self) -> HashMap<&str, &IdlConst> {
+        self.constants
+            .iter()
+            .map(|c| (c.name.as_str(), c))
+            .collect()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IdlMetadata {
     pub name: String,
@@ -66,6 +107,12 @@ pub struct IdlInstruction {
     pub args: Vec<IdlField>,
     #[serde(skip_serializing_if = "is_default")]
     pub returns: Option<IdlType>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub compute_units: Option<u32>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub deprecated: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub deprecation_note: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -107,6 +154,63 @@ pub struct IdlPda {
     pub program: Option<IdlSeed>,
 }
 
+impl IdlPda {
+    /// Render the PDA derivation as a Rust `Pubkey::find_program_address` call, e.g.
+    ///
+    /// ```text
+    /// Pubkey::find_program_address(&[b"seed", authority.as_ref()], &program_id)
+    /// ```
+    ///
+    /// This is a code generation helper: the output is syntactically valid Rust, but it refers to
+    /// the seed paths as variables, which must be in scope for the code to compile.
+    pub fn seeds_to_rust_expression(&self) -> String {
+        let seeds = self
+            .seeds
+            .iter()
+            .map(|seed| match seed {
+                IdlSeed::Const(seed) => bytes_to_rust_expression(&seed.value),
+                IdlSeed::Arg(IdlSeedArg {
+                    path,
+                    encoding: Some(IdlSeedEncoding::LeBytes),
+                }) => format!("{path}.to_le_bytes().as_ref()"),
+                IdlSeed::Arg(IdlSeedArg {
+                    path,
+                    encoding: Some(IdlSeedEncoding::BeBytes),
+                }) => format!("{path}.to_be_bytes().as_ref()"),
+                IdlSeed::Arg(IdlSeedArg { path, .. })
+                | IdlSeed::Account(IdlSeedAccount { path, .. }) => {
+                    format!("{path}.as_ref()")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let program_id = match &self.program {
+            None => "program_id".to_owned(),
+            Some(IdlSeed::Const(seed)) => format!("Pubkey::new_from_array({:?})", seed.value),
+            Some(IdlSeed::Arg(IdlSeedArg { path, .. }))
+            | Some(IdlSeed::Account(IdlSeedAccount { path, .. })) => path.to_owned(),
+        };
+
+        format!("Pubkey::find_program_address(&[{seeds}], &{program_id})")
+    }
+}
+
+/// Render the given bytes as a byte string literal if they're printable ASCII, or as a byte slice
+/// otherwise.
+fn bytes_to_rust_expression(bytes: &[u8]) -> String {
+    let is_printable = |b: &u8| b.is_ascii_graphic() || *b == b' ';
+    if !bytes.is_empty() && bytes.iter().all(is_printable) {
+        let literal = bytes
+            .iter()
+            .flat_map(|b| std::ascii::escape_default(*b))
+            .map(char::from)
+            .collect::<String>();
+        format!("b\"{literal}\"")
+    } else {
+        format!("&{bytes:?}")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 pub enum IdlSeed {
@@ -123,6 +227,18 @@ pub struct IdlSeedConst {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IdlSeedArg {
     pub path: String,
+    /// Byte representation of the argument, if it's a number converted to bytes in the seed
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub encoding: Option<IdlSeedEncoding>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum IdlSeedEncoding {
+    /// `to_le_bytes()`
+    LeBytes,
+    /// `to_be_bytes()`
+    BeBytes,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -197,6 +313,31 @@ pub enum IdlSerialization {
     Custom(String),
 }
 
+impl IdlSerialization {
+    /// Create a [`IdlSerialization::Custom`] in the `custom:<crate_name>:<version>` format.
+    pub fn custom(crate_name: &str, version: &str) -> Self {
+        Self::Custom(format!("custom:{crate_name}:{version}"))
+    }
+
+    /// Whether the serialization is the default, Borsh.
+    pub fn is_borsh(&self) -> bool {
+        matches!(self, Self::Borsh)
+    }
+
+    /// Parse the content of a [`IdlSerialization::Custom`] variant.
+    ///
+    /// The expected format is `custom:<crate_name>:<version>`, e.g. `custom:my-serializer:1.0`.
+    /// Returns the crate name and version, or `None` if the format doesn't match.
+    pub fn parse_custom(s: &str) -> Option<(String, String)> {
+        let (crate_name, version) = s.strip_prefix("custom:")?.split_once(':')?;
+        if crate_name.is_empty() || version.is_empty() || version.contains(':') {
+            return None;
+        }
+
+        Some((crate_name.to_owned(), version.to_owned()))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "kind", rename_all = "lowercase")]
 #[non_exhaustive]
@@ -422,8 +563,9 @@ impl FromStr for IdlType {
                         s.get(i + 1..)
                             .unwrap()
                             .strip_suffix('>')
-                            .ok_or_else(|| anyhow!("Invalid generic syntax: missing '>'"))?
-                            .split(',')
+                            .ok_or_else(|| anyhow!("Invalid generic syntax: missing '>'"))
+                            .and_then(split_generic_args)?
+                            .into_iter()
                             .map(|g| g.trim().to_owned())
                             .map(|g| {
                                 if g.parse::<bool>().is_ok()
@@ -451,6 +593,35 @@ impl FromStr for IdlType {
 
 pub type IdlDiscriminator = Vec<u8>;
 
+/// Split generic arguments on the commas that are not inside of nested generics or arrays, e.g.
+/// `Option<u8>, [u8; 4], Foo<u16, u32>` results in `Option<u8>`, `[u8; 4]` and `Foo<u16, u32>`.
+fn split_generic_args(s: &str) -> Result<Vec<&str>, anyhow::Error> {
+    let mut args = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '[' | '(' => depth += 1,
+            '>' | ']' | ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("Invalid generic syntax: unbalanced '{c}'"))?
+            }
+            ',' if depth == 0 => {
+                args.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return Err(anyhow!("Invalid generic syntax: unclosed generic argument"));
+    }
+
+    args.push(&s[start..]);
+    Ok(args)
+}
+
 fn is_default<T: Default + PartialEq>(it: &T) -> bool {
     *it == T::default()
 }
@@ -541,6 +712,230 @@ mod tests {
         )
     }
 
+    #[test]
+    fn defined_with_nested_generics() {
+        assert_eq!(
+            IdlType::from_str("MyStruct<Option<Pubkey>, Vec<u64>>").unwrap(),
+            IdlType::Defined {
+                name: "MyStruct".into(),
+                generics: vec![
+                    IdlGenericArg::Type {
+                        ty: IdlType::Option(Box::new(IdlType::Pubkey))
+                    },
+                    IdlGenericArg::Type {
+                        ty: IdlType::Vec(Box::new(IdlType::U64))
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            IdlType::from_str("Foo<Option<Vec<u64>>, [u8; 4]>").unwrap(),
+            IdlType::Defined {
+                name: "Foo".into(),
+                generics: vec![
+                    IdlGenericArg::Type {
+                        ty: IdlType::Option(Box::new(IdlType::Vec(Box::new(IdlType::U64))))
+                    },
+                    IdlGenericArg::Type {
+                        ty: IdlType::Array(Box::new(IdlType::U8), IdlArrayLen::Value(4))
+                    },
+                ],
+            }
+        );
+        assert_eq!(
+            IdlType::from_str("Foo<Option<u64>>").unwrap(),
+            IdlType::Defined {
+                name: "Foo".into(),
+                generics: vec![IdlGenericArg::Type {
+                    ty: IdlType::Option(Box::new(IdlType::U64))
+                }],
+            }
+        );
+        assert_eq!(
+            IdlType::from_str("Outer<Inner<u8>>").unwrap(),
+            IdlType::Defined {
+                name: "Outer".into(),
+                generics: vec![IdlGenericArg::Type {
+                    ty: IdlType::Defined {
+                        name: "Inner".into(),
+                        generics: vec![IdlGenericArg::Type { ty: IdlType::U8 }],
+                    }
+                }],
+            }
+        );
+        assert!(IdlType::from_str("Foo<Bar<u8, u16>").is_err());
+    }
+
+    #[test]
+    fn serialization_round_trip() {
+        for serialization in [
+            IdlSerialization::Borsh,
+            IdlSerialization::Bytemuck,
+            IdlSerialization::BytemuckUnsafe,
+            IdlSerialization::custom("my-serializer", "1.0"),
+        ] {
+            let json = serde_json::to_string(&serialization).unwrap();
+            assert_eq!(
+                serde_json::from_str::<IdlSerialization>(&json).unwrap(),
+                serialization
+            );
+        }
+    }
+
+    #[test]
+    fn serialization_is_borsh() {
+        assert!(IdlSerialization::default().is_borsh());
+        assert!(!IdlSerialization::Bytemuck.is_borsh());
+        assert!(!IdlSerialization::custom("my-serializer", "1.0").is_borsh());
+    }
+
+    #[test]
+    fn serialization_parse_custom() {
+        let IdlSerialization::Custom(custom) = IdlSerialization::custom("my-serializer", "1.0")
+        else {
+            panic!("Expected custom serialization");
+        };
+        assert_eq!(custom, "custom:my-serializer:1.0");
+        assert_eq!(
+            IdlSerialization::parse_custom(&custom),
+            Some(("my-serializer".into(), "1.0".into()))
+        );
+
+        assert_eq!(IdlSerialization::parse_custom("my-serializer:1.0"), None);
+        assert_eq!(IdlSerialization::parse_custom("custom:my-serializer"), None);
+        assert_eq!(IdlSerialization::parse_custom("custom::1.0"), None);
+        assert_eq!(IdlSerialization::parse_custom("custom:a:1.0:extra"), None);
+    }
+
+    #[test]
+    fn find_by_name() {
+        let idl = serde_json::from_str::<Idl>(
+            r#"{
+                "address": "id11111111111111111111111111111111111111111",
+                "metadata": { "name": "test", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [
+                    { "name": "init", "discriminator": [1], "accounts": [], "args": [] }
+                ],
+                "accounts": [{ "name": "Data", "discriminator": [2] }],
+                "errors": [{ "code": 6000, "name": "Invalid" }],
+                "types": [
+                    { "name": "Data", "type": { "kind": "struct", "fields": [] } }
+                ],
+                "constants": [
+                    { "name": "FEE", "type": "u64", "value": "30" },
+                    { "name": "Data::MAX", "type": "u8", "value": "255" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(idl.find_instruction("init").is_some());
+        assert!(idl.find_account("Data").is_some());
+        assert!(idl.find_error("Invalid").is_some());
+        assert!(idl.find_type("Data").is_some());
+        assert_eq!(
+            idl.find_constant("FEE").map(|c| c.value.as_str()),
+            Some("30")
+        );
+        assert!(idl.find_constant("Missing").is_none());
+
+        let constants = idl.constants_map();
+        assert_eq!(constants.len(), 2);
+        assert_eq!(
+            constants.get("Data::MAX").map(|c| c.value.as_str()),
+            Some("255")
+        );
+    }
+
+    #[test]
+    fn pda_seeds_to_rust_expression() {
+        let pda = IdlPda {
+            seeds: vec![
+                IdlSeed::Const(IdlSeedConst {
+                    value: b"vault".to_vec(),
+                }),
+                IdlSeed::Const(IdlSeedConst {
+                    value: vec![1, 2, 255],
+                }),
+                IdlSeed::Arg(IdlSeedArg {
+                    path: "data.amount".into(),
+                    encoding: None,
+                }),
+                IdlSeed::Account(IdlSeedAccount {
+                    path: "authority".into(),
+                    account: None,
+                }),
+            ],
+            program: None,
+        };
+        assert_eq!(
+            pda.seeds_to_rust_expression(),
+            "Pubkey::find_program_address(&[b\"vault\", &[1, 2, 255], data.amount.as_ref(), \
+             authority.as_ref()], &program_id)"
+        );
+    }
+
+    #[test]
+    fn pda_seeds_to_rust_expression_with_encoding() {
+        let pda = IdlPda {
+            seeds: vec![
+                IdlSeed::Arg(IdlSeedArg {
+                    path: "index".into(),
+                    encoding: Some(IdlSeedEncoding::LeBytes),
+                }),
+                IdlSeed::Arg(IdlSeedArg {
+                    path: "data.id".into(),
+                    encoding: Some(IdlSeedEncoding::BeBytes),
+                }),
+            ],
+            program: None,
+        };
+        assert_eq!(
+            pda.seeds_to_rust_expression(),
+            "Pubkey::find_program_address(&[index.to_le_bytes().as_ref(), \
+             data.id.to_be_bytes().as_ref()], &program_id)"
+        );
+    }
+
+    #[test]
+    fn pda_seeds_to_rust_expression_with_program() {
+        let pda = IdlPda {
+            seeds: vec![IdlSeed::Const(IdlSeedConst {
+                value: b"a\"b".to_vec(),
+            })],
+            program: Some(IdlSeed::Account(IdlSeedAccount {
+                path: "token_program".into(),
+                account: None,
+            })),
+        };
+        assert_eq!(
+            pda.seeds_to_rust_expression(),
+            "Pubkey::find_program_address(&[b\"a\\\"b\"], &token_program)"
+        );
+    }
+
+    #[test]
+    fn seed_arg_encoding_serialization() {
+        let seed = IdlSeed::Arg(IdlSeedArg {
+            path: "index".into(),
+            encoding: Some(IdlSeedEncoding::LeBytes),
+        });
+        let json = serde_json::to_string(&seed).unwrap();
+        assert_eq!(
+            json,
+            r#"{"kind":"arg","path":"index","encoding":"le_bytes"}"#
+        );
+
+        let seed = serde_json::from_str::<IdlSeed>(r#"{"kind":"arg","path":"index"}"#).unwrap();
+        assert_eq!(
+            seed,
+            IdlSeed::Arg(IdlSeedArg {
+                path: "index".into(),
+                encoding: None,
+            })
+        );
+    }
+
     #[test]
     fn array_missing_semicolon_error() {
         let result = IdlType::from_str("[u8 32]");
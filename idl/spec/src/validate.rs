@@ -0,0 +1,343 @@
+use {
+    crate::{
+        Idl, IdlDefinedFields, IdlGenericArg, IdlInstructionAccountItem, IdlPda, IdlSeed,
+        IdlSeedAccount, IdlType, IdlTypeDefTy,
+    },
+    std::{
+        collections::{HashMap, HashSet},
+        fmt,
+    },
+};
+
+/// A semantic inconsistency found in an IDL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdlValidationError {
+    /// JSON path of the invalid value, e.g. `instructions[0].args[1].type`
+    pub path: String,
+    /// Human-readable description of the problem
+    pub message: String,
+}
+
+impl fmt::Display for IdlValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for IdlValidationError {}
+
+impl Idl {
+    /// Check the IDL for semantic inconsistencies that are not caught by deserialization.
+    ///
+    /// The following are checked:
+    ///
+    /// - All defined type references resolve to a type in `types`
+    /// - All accounts and events have a type definition in `types`
+    /// - All `account` references of PDA seeds resolve to a type in `types`
+    /// - Error codes are unique
+    ///
+    /// Returns an empty list if the IDL is consistent.
+    pub fn validate(&self) -> Vec<IdlValidationError> {
+        let mut validator = Validator {
+            type_names: self.types.iter().map(|ty| ty.name.as_str()).collect(),
+            errors: vec![],
+        };
+        validator.validate(self);
+        validator.errors
+    }
+}
+
+struct Validator<'a> {
+    type_names: HashSet<&'a str>,
+    errors: Vec<IdlValidationError>,
+}
+
+impl Validator<'_> {
+    fn validate(&mut self, idl: &Idl) {
+        for (i, ix) in idl.instructions.iter().enumerate() {
+            let path = format!("instructions[{i}]");
+            self.validate_instruction_accounts(&ix.accounts, &format!("{path}.accounts"));
+            for (j, arg) in ix.args.iter().enumerate() {
+                self.validate_type(&arg.ty, &format!("{path}.args[{j}].type"));
+            }
+            if let Some(returns) = &ix.returns {
+                self.validate_type(returns, &format!("{path}.returns"));
+            }
+        }
+
+        for (i, acc) in idl.accounts.iter().enumerate() {
+            self.validate_type_def_exists(&acc.name, &format!("accounts[{i}]"), "Account");
+        }
+        for (i, ev) in idl.events.iter().enumerate() {
+            self.validate_type_def_exists(&ev.name, &format!("events[{i}]"), "Event");
+        }
+
+        let mut codes = HashMap::new();
+        for (i, err) in idl.errors.iter().enumerate() {
+            if let Some(prev) = codes.insert(err.code, &err.name) {
+                self.push(
+                    format!("errors[{i}].code"),
+                    format!(
+                        "Error code {} of `{}` is already used by `{prev}`",
+                        err.code, err.name
+                    ),
+                );
+            }
+        }
+
+        for (i, ty_def) in idl.types.iter().enumerate() {
+            let path = format!("types[{i}].type");
+            match &ty_def.ty {
+                IdlTypeDefTy::Struct { fields } => {
+                    if let Some(fields) = fields {
+                        self.validate_fields(fields, &format!("{path}.fields"));
+                    }
+                }
+                IdlTypeDefTy::Enum { variants } => {
+                    for (j, variant) in variants.iter().enumerate() {
+                        if let Some(fields) = &variant.fields {
+                            self.validate_fields(fields, &format!("{path}.variants[{j}].fields"));
+                        }
+                    }
+                }
+                IdlTypeDefTy::Type { alias } => self.validate_type(alias, &format!("{path}.alias")),
+            }
+        }
+
+        for (i, constant) in idl.constants.iter().enumerate() {
+            self.validate_type(&constant.ty, &format!("constants[{i}].type"));
+        }
+    }
+
+    fn validate_instruction_accounts(
+        &mut self,
+        accounts: &[IdlInstructionAccountItem],
+        path: &str,
+    ) {
+        for (i, acc) in accounts.iter().enumerate() {
+            let path = format!("{path}[{i}]");
+            match acc {
+                IdlInstructionAccountItem::Composite(composite) => {
+                    self.validate_instruction_accounts(
+                        &composite.accounts,
+                        &format!("{path}.accounts"),
+                    );
+                }
+                IdlInstructionAccountItem::Single(single) => {
+                    if let Some(pda) = &single.pda {
+                        self.validate_pda(pda, &format!("{path}.pda"));
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_pda(&mut self, pda: &IdlPda, path: &str) {
+        let seeds = pda
+            .seeds
+            .iter()
+            .enumerate()
+            .map(|(i, seed)| (seed, format!("{path}.seeds[{i}]")));
+        let program = pda
+            .program
+            .iter()
+            .map(|seed| (seed, format!("{path}.program")));
+        for (seed, path) in seeds.chain(program) {
+            if let IdlSeed::Account(IdlSeedAccount {
+                account: Some(account),
+                ..
+            }) = seed
+            {
+                if !self.type_names.contains(account.as_str()) {
+                    self.push(
+                        format!("{path}.account"),
+                        format!("Seed account type `{account}` not found in `types`"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_fields(&mut self, fields: &IdlDefinedFields, path: &str) {
+        match fields {
+            IdlDefinedFields::Named(fields) => {
+                for (i, field) in fields.iter().enumerate() {
+                    self.validate_type(&field.ty, &format!("{path}[{i}].type"));
+                }
+            }
+            IdlDefinedFields::Tuple(tys) => {
+                for (i, ty) in tys.iter().enumerate() {
+                    self.validate_type(ty, &format!("{path}[{i}]"));
+                }
+            }
+        }
+    }
+
+    fn validate_type(&mut self, ty: &IdlType, path: &str) {
+        match ty {
+            IdlType::Option(ty) => self.validate_type(ty, &format!("{path}.option")),
+            IdlType::Vec(ty) => self.validate_type(ty, &format!("{path}.vec")),
+            IdlType::Array(ty, _) => self.validate_type(ty, &format!("{path}.array[0]")),
+            IdlType::Defined { name, generics } => {
+                if !self.type_names.contains(name.as_str()) {
+                    self.push(
+                        format!("{path}.defined"),
+                        format!("Type `{name}` not found in `types`"),
+                    );
+                }
+                for (i, generic) in generics.iter().enumerate() {
+                    if let IdlGenericArg::Type { ty } = generic {
+                        self.validate_type(ty, &format!("{path}.defined.generics[{i}].type"));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn validate_type_def_exists(&mut self, name: &str, path: &str, kind: &str) {
+        if !self.type_names.contains(name) {
+            self.push(
+                path.to_owned(),
+                format!("{kind} `{name}` does not have a type definition in `types`"),
+            );
+        }
+    }
+
+    fn push(&mut self, path: String, message: String) {
+        self.errors.push(IdlValidationError { path, message });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::*};
+
+    fn idl() -> Idl {
+        serde_json::from_str(
+            r#"{
+                "address": "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+                "metadata": { "name": "test", "version": "0.1.0", "spec": "0.1.0" },
+                "instructions": [
+                    {
+                        "name": "initialize",
+                        "discriminator": [0, 0, 0, 0, 0, 0, 0, 0],
+                        "accounts": [
+                            {
+                                "name": "data",
+                                "pda": {
+                                    "seeds": [
+                                        { "kind": "const", "value": [1] },
+                                        {
+                                            "kind": "account",
+                                            "path": "state.authority",
+                                            "account": "State"
+                                        }
+                                    ]
+                                }
+                            }
+                        ],
+                        "args": [
+                            { "name": "params", "type": { "defined": { "name": "Params" } } }
+                        ]
+                    }
+                ],
+                "accounts": [{ "name": "State", "discriminator": [1, 1, 1, 1, 1, 1, 1, 1] }],
+                "errors": [
+                    { "code": 6000, "name": "First" },
+                    { "code": 6001, "name": "Second" }
+                ],
+                "types": [
+                    {
+                        "name": "State",
+                        "type": {
+                            "kind": "struct",
+                            "fields": [{ "name": "authority", "type": "pubkey" }]
+                        }
+                    },
+                    {
+                        "name": "Params",
+                        "type": {
+                            "kind": "struct",
+                            "fields": [
+                                { "name": "values", "type": { "vec": "u64" } }
+                            ]
+                        }
+                    }
+                ]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(idl().validate(), vec![]);
+    }
+
+    #[test]
+    fn missing_defined_type() {
+        let mut idl = idl();
+        idl.types.retain(|ty| ty.name != "Params");
+        assert_eq!(
+            idl.validate(),
+            vec![IdlValidationError {
+                path: "instructions[0].args[0].type.defined".into(),
+                message: "Type `Params` not found in `types`".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn nested_missing_defined_type() {
+        let mut idl = idl();
+        idl.constants.push(IdlConst {
+            name: "C".into(),
+            docs: vec![],
+            ty: IdlType::Option(Box::new(IdlType::Defined {
+                name: "Missing".into(),
+                generics: vec![],
+            })),
+            value: "None".into(),
+        });
+        assert_eq!(
+            idl.validate(),
+            vec![IdlValidationError {
+                path: "constants[0].type.option.defined".into(),
+                message: "Type `Missing` not found in `types`".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn missing_account_type() {
+        let mut idl = idl();
+        idl.types.retain(|ty| ty.name != "State");
+        assert_eq!(
+            idl.validate(),
+            vec![
+                IdlValidationError {
+                    path: "instructions[0].accounts[0].pda.seeds[1].account".into(),
+                    message: "Seed account type `State` not found in `types`".into(),
+                },
+                IdlValidationError {
+                    path: "accounts[0]".into(),
+                    message: "Account `State` does not have a type definition in `types`".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicate_error_code() {
+        let mut idl = idl();
+        idl.errors[1].code = 6000;
+        assert_eq!(
+            idl.validate(),
+            vec![IdlValidationError {
+                path: "errors[1].code".into(),
+                message: "Error code 6000 of `Second` is already used by `First`".into(),
+            }]
+        );
+    }
+}
@@ -309,7 +309,15 @@ fn install_toolchain_if_needed(toolchain: &str) -> Result<()> {
 }
 
 /// Convert paths to name if there are no conflicts.
-fn convert_module_paths(idl: Idl) -> Idl {
+///
+/// Constant names are kept as is because associated constants are named `Type::CONST`.
+fn convert_module_paths(mut idl: Idl) -> Idl {
+    // Prefix constant names so that they don't match the path regex
+    const CONSTANT_NAME_PREFIX: &str = "const:";
+    for constant in &mut idl.constants {
+        constant.name.insert_str(0, CONSTANT_NAME_PREFIX);
+    }
+
     let idl = serde_json::to_string(&idl).unwrap();
     let idl = Regex::new(r#""(\w+::)+(\w+)""#)
         .unwrap()
@@ -332,7 +340,14 @@ fn convert_module_paths(idl: Idl) -> Idl {
             }
         });
 
-    serde_json::from_str(&idl).expect("Invalid IDL")
+    let mut idl: Idl = serde_json::from_str(&idl).expect("Invalid IDL");
+    for constant in &mut idl.constants {
+        if let Some(name) = constant.name.strip_prefix(CONSTANT_NAME_PREFIX) {
+            constant.name = name.to_owned();
+        }
+    }
+
+    idl
 }
 
 /// Alphabetically sort fields for consistency.
@@ -362,7 +377,24 @@ fn verify(idl: &Idl) -> Result<()> {
     }
     check_empty_discriminators!(accounts);
     check_empty_discriminators!(events);
-    check_empty_discriminators!(instructions);
+
+    // Only a single instruction is allowed to skip the discriminator. An empty instruction
+    // discriminator matches any instruction data, so clients (`declare_program!` parsers and the
+    // TS instruction coder) must only use it when none of the other discriminators match.
+    let mut empty_discriminator_ixs = idl
+        .instructions
+        .iter()
+        .filter(|ix| ix.discriminator.is_empty());
+    if let (Some(first), Some(second)) = (
+        empty_discriminator_ixs.next(),
+        empty_discriminator_ixs.next(),
+    ) {
+        return Err(anyhow!(
+            "Empty discriminators are not allowed for more than one instruction: `{}` and `{}`",
+            first.name,
+            second.name
+        ));
+    }
 
     // Check potential discriminator collisions
     macro_rules! check_discriminator_collision {
@@ -371,6 +403,7 @@ fn verify(idl: &Idl) -> Result<()> {
                 idl.$field
                     .iter()
                     .filter(|inner| inner.name != outer.name)
+                    .filter(|inner| !inner.discriminator.is_empty())
                     .find(|inner| outer.discriminator.starts_with(&inner.discriminator))
                     .map(|inner| (outer, inner))
             }) {
@@ -338,6 +338,9 @@ mod legacy {
                 accounts: value.accounts.into_iter().map(Into::into).collect(),
                 args: value.args.into_iter().map(Into::into).collect(),
                 returns: value.returns.map(|r| r.into()),
+                compute_units: None,
+                deprecated: false,
+                deprecation_note: None,
             }
         }
     }
@@ -550,7 +553,10 @@ mod legacy {
                     account: seed.account,
                     path: seed.path,
                 }),
-                IdlSeed::Arg(seed) => Self::Arg(t::IdlSeedArg { path: seed.path }),
+                IdlSeed::Arg(seed) => Self::Arg(t::IdlSeedArg {
+                    path: seed.path,
+                    encoding: None,
+                }),
                 IdlSeed::Const(seed) => Self::Const(t::IdlSeedConst {
                     value: match seed.ty {
                         IdlType::String => seed.value.to_string().as_bytes().into(),
@@ -9,7 +9,7 @@ use {
         parse_macro_input,
         spanned::Spanned,
         token::Paren,
-        Expr, Ident, LitStr, Token,
+        Expr, Ident, LitStr, MetaNameValue, Path, Token,
     },
 };
 
@@ -58,6 +58,27 @@ mod lazy;
 ///
 /// All-zeroed discriminators are not supported.
 ///
+/// - `runtime_discriminator`: Override the check of the discriminator when deserializing
+///
+///     **Usage:** `runtime_discriminator = <PATH>`
+///
+///     The path must resolve to a `fn(&[u8]) -> bool`, which is used as [`Discriminator::runtime_discriminator`](./trait.Discriminator.html#method.runtime_discriminator).
+///
+///     This is useful for accepting legacy discriminators of accounts that are being migrated.
+///
+///     Accepted discriminators must have the same length as the discriminator.
+///
+///     **Example:**
+///
+///     ```ignore
+///     fn accept_legacy(data: &[u8]) -> bool {
+///         data.starts_with(MyAccount::DISCRIMINATOR) || data.starts_with(LEGACY_DISC)
+///     }
+///
+///     #[account(runtime_discriminator = accept_legacy)]
+///     pub struct MyAccount {}
+///     ```
+///
 /// # Zero Copy Deserialization
 ///
 /// **WARNING**: Zero copy deserialization is an experimental feature. It's
@@ -159,6 +180,14 @@ pub fn account(
         }
     };
 
+    let runtime_discriminator = args.runtime_discriminator.map(|path| {
+        quote! {
+            fn runtime_discriminator(data: &[u8]) -> bool {
+                #path(data)
+            }
+        }
+    });
+
     let disc = if account_strct.generics.lt_token.is_some() {
         quote! { #account_name::#type_gen::DISCRIMINATOR }
     } else {
@@ -223,6 +252,8 @@ pub fn account(
                 #[automatically_derived]
                 impl #impl_gen anchor_lang::Discriminator for #account_name #type_gen #where_clause {
                     const DISCRIMINATOR: &'static [u8] = #discriminator;
+
+                    #runtime_discriminator
                 }
 
                 // This trait is useful for clients deserializing accounts.
@@ -233,8 +264,7 @@ pub fn account(
                         if buf.len() < #disc.len() {
                             return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
                         }
-                        let given_disc = &buf[..#disc.len()];
-                        if #disc != given_disc {
+                        if !<Self as anchor_lang::Discriminator>::runtime_discriminator(buf) {
                             return Err(anchor_lang::error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch).with_account_name(#account_name_str));
                         }
                         Self::try_deserialize_unchecked(buf)
@@ -284,8 +314,7 @@ pub fn account(
                         if buf.len() < #disc.len() {
                             return Err(anchor_lang::error::ErrorCode::AccountDiscriminatorNotFound.into());
                         }
-                        let given_disc = &buf[..#disc.len()];
-                        if #disc != given_disc {
+                        if !<Self as anchor_lang::Discriminator>::runtime_discriminator(buf) {
                             return Err(anchor_lang::error!(anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch).with_account_name(#account_name_str));
                         }
                         Self::try_deserialize_unchecked(buf)
@@ -301,6 +330,8 @@ pub fn account(
                 #[automatically_derived]
                 impl #impl_gen anchor_lang::Discriminator for #account_name #type_gen #where_clause {
                     const DISCRIMINATOR: &'static [u8] = #discriminator;
+
+                    #runtime_discriminator
                 }
 
                 #owner_impl
@@ -319,6 +350,8 @@ struct AccountArgs {
     namespace: Option<String>,
     /// Named overrides
     overrides: Option<Overrides>,
+    /// Function to check the discriminator with when deserializing
+    runtime_discriminator: Option<Path>,
 }
 
 impl Parse for AccountArgs {
@@ -336,6 +369,9 @@ impl Parse for AccountArgs {
                 AccountArg::Overrides(ov) => {
                     parsed.overrides.replace(ov);
                 }
+                AccountArg::RuntimeDiscriminator(path) => {
+                    parsed.runtime_discriminator.replace(path);
+                }
             }
         }
 
@@ -347,6 +383,7 @@ enum AccountArg {
     ZeroCopy { is_unsafe: bool },
     Namespace(String),
     Overrides(Overrides),
+    RuntimeDiscriminator(Path),
 }
 
 impl Parse for AccountArg {
@@ -383,10 +420,24 @@ impl Parse for AccountArg {
             return Ok(Self::ZeroCopy { is_unsafe });
         }
 
+        // Runtime discriminator
+        if input
+            .fork()
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "runtime_discriminator")
+        {
+            input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            return input.parse::<Path>().map(Self::RuntimeDiscriminator);
+        }
+
         // Overrides (handles discriminator = ...)
+        // Only a single `name = value` argument is parsed so that the remaining arguments are
+        // handled by their own parsers.
         // This will catch invalid arguments like `size = 1234` and provide
         // an informative error message via Overrides::parse
-        input.parse::<Overrides>().map(Self::Overrides)
+        let arg = input.parse::<MetaNameValue>()?;
+        syn::parse2::<Overrides>(arg.into_token_stream()).map(Self::Overrides)
     }
 }
 
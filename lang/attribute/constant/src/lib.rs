@@ -2,6 +2,9 @@ extern crate proc_macro;
 
 /// A marker attribute used to mark const values that should be included in the
 /// generated IDL but functionally does nothing.
+///
+/// It can also be used on inherent `impl` blocks to include their public
+/// associated constants in the IDL, with the name `Type::CONST`.
 #[proc_macro_attribute]
 pub fn constant(
     _attr: proc_macro::TokenStream,
@@ -23,6 +26,13 @@ pub fn constant(
                     #idl_print
                 }
             }
+            syn::Item::Impl(item) => {
+                let idl_print = anchor_syn::idl::gen_idl_print_fn_associated_constants(&item);
+                quote! {
+                    #item
+                    #idl_print
+                }
+            }
             item => quote! {#item},
         };
 
@@ -186,9 +186,16 @@ fn gen_instruction(idl: &Idl) -> proc_macro2::TokenStream {
             quote! { client::accounts::#name { #(#fields,)* } }
         }
 
-        let all_ix_accs = get_all_instruction_accounts(idl);
-        idl.instructions
+        // Instructions without a discriminator match any data, so they're checked last (same as
+        // the on-chain dispatch)
+        let (ixs, empty_discriminator_ixs): (Vec<_>, Vec<_>) = idl
+            .instructions
             .iter()
+            .partition(|ix| !ix.discriminator.is_empty());
+
+        let all_ix_accs = get_all_instruction_accounts(idl);
+        ixs.into_iter()
+            .chain(empty_discriminator_ixs)
             .map(|ix| {
                 let name = format_ident!("{}", ix.name.to_camel_case());
                 let accounts = gen_accounts(&ix.name, &ix.accounts, &all_ix_accs);
@@ -95,6 +95,16 @@ pub fn declare_program(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
 ///     - `discriminator = MY_DISC`
 ///     - `discriminator = get_disc(...)`
 ///
+/// - `skip_discriminator`: Don't use a discriminator for the instruction
+///
+///     **Usage:** `skip_discriminator`
+///
+///     The instruction data is deserialized starting from the first byte.
+///
+///     The instruction is only dispatched when no other instruction matches the data.
+///
+///     It can only be used by one instruction and cannot be used with `discriminator`.
+///
 /// # Example
 ///
 /// ```ignore
@@ -126,3 +136,41 @@ pub fn instruction(
     // the arguments to transform the instruction.
     input
 }
+
+/// This attribute is used to record the expected compute units of a program instruction in the
+/// IDL.
+///
+/// Clients can use the value to request the right compute unit limit, e.g. with
+/// `ComputeBudgetInstruction::set_compute_unit_limit`, instead of relying on the default.
+///
+/// # Example
+///
+/// ```ignore
+/// use anchor_lang::prelude::*;
+///
+/// declare_id!("ComputeUnits1111111111111111111111111111111");
+///
+/// #[program]
+/// pub mod compute_units {
+///     use super::*;
+///
+///     #[compute_units(5_000)]
+///     pub fn my_ix(_ctx: Context<MyIx>) -> Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Accounts)]
+/// pub struct MyIx<'info> {
+///     pub signer: Signer<'info>,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn compute_units(
+    _args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    // This macro itself is a no-op, but the `#[program]` macro will detect this attribute and
+    // store the value in the IDL.
+    input
+}
@@ -40,6 +40,13 @@ fn extract_borsh_attrs(input: &mut DeriveInput) -> Vec<syn::Meta> {
         .collect()
 }
 
+/// Remove the IDL-only `#[serialization]` attribute, which the borsh derives don't recognize.
+fn strip_serialization_attr(input: &mut DeriveInput) {
+    input
+        .attrs
+        .retain(|attr| !attr.path().is_ident("serialization"));
+}
+
 /// Locate any `#[borsh]` attributes on struct/enum fields,
 /// which are currently unsupported with `lazy-account`.
 #[cfg(feature = "lazy-account")]
@@ -67,6 +74,7 @@ fn find_field_borsh_attr(input: &DeriveInput) -> Option<&syn::Attribute> {
 
 fn gen_borsh_serialize(input: TokenStream) -> TokenStream {
     let mut item = parse_macro_input!(input as DeriveInput);
+    strip_serialization_attr(&mut item);
     let borsh_attrs = extract_borsh_attrs(&mut item);
     let attrs = helper_attrs("BorshSerialize", borsh_attrs);
     quote! {
@@ -76,7 +84,21 @@ fn gen_borsh_serialize(input: TokenStream) -> TokenStream {
     .into()
 }
 
-#[proc_macro_derive(AnchorSerialize, attributes(borsh))]
+/// Implements `borsh` serialization for this structure, as well as emitting IDL type
+/// information when the `idl-build` feature is enabled.
+///
+/// Types that are serialized with a custom serializer can declare it in the IDL with the
+/// `#[serialization = "custom:<crate_name>:<version>"]` attribute.
+///
+/// ```
+/// # use anchor_derive_serde::AnchorSerialize;
+/// #[derive(AnchorSerialize)]
+/// #[serialization = "custom:my-serializer:1.0"]
+/// pub struct Example {
+///     x: u8,
+/// }
+/// ```
+#[proc_macro_derive(AnchorSerialize, attributes(borsh, serialization))]
 pub fn anchor_serialize(input: TokenStream) -> TokenStream {
     #[cfg(not(feature = "idl-build"))]
     let ret = gen_borsh_serialize(input);
@@ -126,6 +148,7 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream {
         .into();
     }
 
+    strip_serialization_attr(&mut item);
     let borsh_attrs = extract_borsh_attrs(&mut item);
     #[cfg(feature = "lazy-account")]
     {
@@ -170,6 +193,8 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream {
 /// `use_discriminant = true` and other `#[borsh]` attributes (e.g. `skip`) are not yet
 /// supported in conjunction with `lazy-account`.
 ///
+/// The IDL-only `#[serialization]` attribute is accepted, see [`AnchorSerialize`].
+///
 /// ```
 /// # use anchor_derive_serde::AnchorDeserialize;
 /// #[derive(AnchorDeserialize)]
@@ -193,7 +218,7 @@ fn gen_borsh_deserialize(input: TokenStream) -> TokenStream {
 ///     x: u8,
 /// }
 /// ```
-#[proc_macro_derive(AnchorDeserialize, attributes(borsh))]
+#[proc_macro_derive(AnchorDeserialize, attributes(borsh, serialization))]
 pub fn anchor_deserialize(input: TokenStream) -> TokenStream {
     #[cfg(feature = "lazy-account")]
     {
@@ -20,6 +20,10 @@ use {
 /// For types that have a variable size like String and Vec, it is necessary to indicate the size by the `max_len` attribute.
 /// For nested types, it is necessary to specify a size for each variable type (see example).
 ///
+/// Structs that only have primitive, `Pubkey`, array or tuple fields also implement
+/// [`StaticSpace`](./trait.StaticSpace.html). If such a struct is `#[repr(C)]`, a warning is
+/// emitted when its `INIT_SPACE` is different from its `size_of`, which usually indicates padding.
+///
 /// # Example
 /// ```ignore
 /// use anchor_lang::prelude::*;
@@ -49,17 +53,44 @@ pub fn derive_init_space(item: TokenStream) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let name = input.ident.clone();
 
+    let is_repr_c = is_repr_c(&input.attrs);
+    let is_generic = !input.generics.params.is_empty();
+
     let process_struct_fields = |fields: Punctuated<Field, Comma>| {
+        let is_static = fields.iter().all(|f| is_static_type(&f.ty));
         let recurse = fields.into_iter().map(|f| {
             let mut max_len_args = get_max_len_args(&f.attrs);
             len_from_type(f.ty, &mut max_len_args)
         });
 
+        let static_space = if is_static {
+            let check = if is_repr_c && !is_generic {
+                quote_spanned! {name.span() =>
+                    const _: () = anchor_lang::__private::StaticSpaceCheck::<{
+                        <#name as anchor_lang::Space>::INIT_SPACE != ::core::mem::size_of::<#name>()
+                    }>::check();
+                }
+            } else {
+                quote!()
+            };
+
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics anchor_lang::StaticSpace for #name #ty_generics #where_clause {}
+
+                #check
+            }
+        } else {
+            quote!()
+        };
+
         quote! {
             #[automatically_derived]
             impl #impl_generics anchor_lang::Space for #name #ty_generics #where_clause {
                 const INIT_SPACE: usize = 0 #(+ #recurse)*;
             }
+
+            #static_space
         }
     };
 
@@ -72,6 +103,9 @@ pub fn derive_init_space(item: TokenStream) -> TokenStream {
                 impl #impl_generics anchor_lang::Space for #name #ty_generics #where_clause {
                     const INIT_SPACE: usize = 0;
                 }
+
+                #[automatically_derived]
+                impl #impl_generics anchor_lang::StaticSpace for #name #ty_generics #where_clause {}
             },
         },
         syn::Data::Enum(enm) => {
@@ -189,6 +223,46 @@ fn len_from_type(ty: Type, attrs: &mut Option<VecDeque<TokenStream2>>) -> TokenS
     }
 }
 
+/// Whether the type is made up of only primitives and `Pubkey`s, i.e. its space doesn't depend on
+/// `max_len` or on other user defined types.
+fn is_static_type(ty: &Type) -> bool {
+    match ty {
+        Type::Array(TypeArray { elem, .. }) => is_static_type(elem),
+        Type::Tuple(ty_tuple) => ty_tuple.elems.iter().all(is_static_type),
+        Type::Path(ty_path) => ty_path.path.segments.last().is_some_and(|seg| {
+            seg.arguments.is_none()
+                && matches!(
+                    seg.ident.to_string().as_str(),
+                    "i8" | "u8"
+                        | "bool"
+                        | "i16"
+                        | "u16"
+                        | "i32"
+                        | "u32"
+                        | "f32"
+                        | "i64"
+                        | "u64"
+                        | "f64"
+                        | "i128"
+                        | "u128"
+                        | "Pubkey"
+                )
+        }),
+        _ => false,
+    }
+}
+
+fn is_repr_c(attributes: &[Attribute]) -> bool {
+    attributes
+        .iter()
+        .filter(|a| a.path().is_ident("repr"))
+        .filter_map(|a| {
+            a.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)
+                .ok()
+        })
+        .any(|reprs| reprs.iter().any(|repr| repr == "C"))
+}
+
 fn get_first_ty_arg(args: &PathArguments) -> Option<Type> {
     match args {
         PathArguments::AngleBracketed(bracket) => bracket.args.iter().find_map(|el| match el {
@@ -7,8 +7,8 @@ use {
         solana_program::{
             account_info::AccountInfo, instruction::AccountMeta, pubkey::Pubkey, system_program,
         },
-        AccountDeserialize, AccountSerialize, Accounts, AccountsClose, AccountsExit, Key, Owner,
-        Result, ToAccountInfos, ToAccountMetas,
+        AccountDeserialize, AccountSerialize, Accounts, AccountsClose, AccountsExit, Discriminator,
+        Key, Owner, Result, ToAccountInfos, ToAccountMetas,
     },
     std::{
         collections::BTreeSet,
@@ -290,6 +290,22 @@ impl<'a, T: AccountSerialize + AccountDeserialize + Clone> Account<'a, T> {
     pub fn set_inner(&mut self, inner: T) {
         self.account = inner;
     }
+
+    /// Deserializes `T` from `buf` after skipping the first `offset` bytes, without checking the
+    /// account discriminator. Be careful when using this and avoid it if possible.
+    ///
+    /// This is useful for migrating legacy accounts that have a custom header before the
+    /// discriminator, where `offset` is the length of the header.
+    pub fn try_deserialize_unchecked_with_offset(buf: &[u8], offset: usize) -> Result<T>
+    where
+        T: Discriminator,
+    {
+        let mut data = buf
+            .get(offset..)
+            .filter(|data| data.len() >= T::DISCRIMINATOR.len())
+            .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+        T::try_deserialize_unchecked(&mut data)
+    }
 }
 
 impl<'a, T: AccountSerialize + AccountDeserialize + Owner + Clone> Account<'a, T> {
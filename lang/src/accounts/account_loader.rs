@@ -135,8 +135,7 @@ impl<'info, T: ZeroCopy + Owner> AccountLoader<'info, T> {
             return Err(ErrorCode::AccountDiscriminatorNotFound.into());
         }
 
-        let given_disc = &data[..disc.len()];
-        if given_disc != disc {
+        if !T::runtime_discriminator(data) {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
@@ -164,8 +163,7 @@ impl<'info, T: ZeroCopy + Owner> AccountLoader<'info, T> {
             return Err(ErrorCode::AccountDiscriminatorNotFound.into());
         }
 
-        let given_disc = &data[..disc.len()];
-        if given_disc != disc {
+        if !T::runtime_discriminator(&data) {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
@@ -188,8 +186,7 @@ impl<'info, T: ZeroCopy + Owner> AccountLoader<'info, T> {
             return Err(ErrorCode::AccountDiscriminatorNotFound.into());
         }
 
-        let given_disc = &data[..disc.len()];
-        if given_disc != disc {
+        if !T::runtime_discriminator(&data) {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
@@ -219,8 +219,7 @@ where
             return Err(ErrorCode::AccountDiscriminatorNotFound.into());
         }
 
-        let given_disc = &data[..disc.len()];
-        if given_disc != disc {
+        if !T::runtime_discriminator(data) {
             return Err(ErrorCode::AccountDiscriminatorMismatch.into());
         }
 
@@ -208,6 +208,21 @@ where
         }
     }
 
+    #[must_use]
+    pub fn new_with_signer_and_remaining(
+        program_id: Pubkey,
+        accounts: T,
+        signer_seeds: &'a [&'b [&'c [u8]]],
+        remaining_accounts: Vec<AccountInfo<'info>>,
+    ) -> Self {
+        Self {
+            accounts,
+            program_id,
+            signer_seeds,
+            remaining_accounts,
+        }
+    }
+
     #[must_use]
     pub fn with_signer(mut self, signer_seeds: &'a [&'b [&'c [u8]]]) -> Self {
         self.signer_seeds = signer_seeds;
@@ -219,6 +234,21 @@ where
         self.remaining_accounts = ra;
         self
     }
+
+    /// Append a remaining account, passing it to the CPI with the given `is_writable` and
+    /// `is_signer` flags instead of the ones of `info`.
+    #[must_use]
+    pub fn add_remaining_account(
+        mut self,
+        mut info: AccountInfo<'info>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> Self {
+        info.is_writable = is_writable;
+        info.is_signer = is_signer;
+        self.remaining_accounts.push(info);
+        self
+    }
 }
 
 impl<'info, T: ToAccountInfos<'info> + ToAccountMetas> ToAccountInfos<'info>
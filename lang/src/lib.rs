@@ -60,7 +60,7 @@ pub use {
     anchor_attribute_constant::constant,
     anchor_attribute_error::*,
     anchor_attribute_event::{emit, event},
-    anchor_attribute_program::{declare_program, instruction, program},
+    anchor_attribute_program::{compute_units, declare_program, instruction, program},
     anchor_derive_accounts::Accounts,
     anchor_derive_serde::{__erase, AnchorDeserialize, AnchorSerialize},
     anchor_derive_space::InitSpace,
@@ -415,6 +415,19 @@ pub trait Discriminator {
     ///
     /// See [`Discriminator`] trait documentation for more information.
     const DISCRIMINATOR: &'static [u8];
+
+    /// Check whether the given data starts with a discriminator of this type.
+    ///
+    /// This is used when deserializing accounts, and it only accepts [`Self::DISCRIMINATOR`] by
+    /// default. It can be overridden to also accept other discriminators, e.g. legacy
+    /// discriminators of accounts that are being migrated. For `#[account]` types, use the
+    /// `runtime_discriminator` argument of the attribute to override it.
+    ///
+    /// Accepted discriminators must have the same length as [`Self::DISCRIMINATOR`] because the
+    /// account data is read after `DISCRIMINATOR.len()` bytes.
+    fn runtime_discriminator(data: &[u8]) -> bool {
+        data.starts_with(Self::DISCRIMINATOR)
+    }
 }
 
 /// Defines the space of an account for initialization.
@@ -479,6 +492,30 @@ impl Space for Pubkey {
     const INIT_SPACE: usize = 32;
 }
 
+/// Marker for types whose [`Space::INIT_SPACE`] doesn't depend on any `max_len`, i.e. types that
+/// don't have dynamically sized fields such as `Vec` or `String`.
+///
+/// `#[derive(InitSpace)]` implements this trait for structs whose fields are primitives,
+/// `Pubkey`s, or arrays and tuples of them. For `#[repr(C)]` structs, it also warns at compile
+/// time when `INIT_SPACE` is different from `size_of::<Self>()`, which usually means the struct
+/// has padding.
+pub trait StaticSpace: Space {}
+
+impl StaticSpace for bool {}
+impl StaticSpace for u8 {}
+impl StaticSpace for u16 {}
+impl StaticSpace for u32 {}
+impl StaticSpace for u64 {}
+impl StaticSpace for u128 {}
+impl StaticSpace for i8 {}
+impl StaticSpace for i16 {}
+impl StaticSpace for i32 {}
+impl StaticSpace for i64 {}
+impl StaticSpace for i128 {}
+impl StaticSpace for f32 {}
+impl StaticSpace for f64 {}
+impl StaticSpace for Pubkey {}
+
 /// Bump seed for program derived addresses.
 pub trait Bump {
     fn seed(&self) -> u8;
@@ -568,7 +605,7 @@ pub mod prelude {
                 signer::Signer, system_account::SystemAccount, sysvar::Sysvar,
                 unchecked_account::UncheckedAccount,
             },
-            constant,
+            compute_units, constant,
             context::{Context, CpiContext},
             declare_id, declare_program, emit, err, error, event, instruction, program, pubkey,
             require, require_eq, require_gt, require_gte, require_keys_eq, require_keys_neq,
@@ -578,8 +615,8 @@ pub mod prelude {
             system_program::System,
             zero_copy, AccountDeserialize, AccountSerialize, Accounts, AccountsClose, AccountsExit,
             AnchorDeserialize, AnchorSerialize, Discriminator, DuplicateMutableAccountKeys, Id,
-            InitSpace, Key, Lamports, Owner, Owners, ProgramData, Result, Space, ToAccountInfo,
-            ToAccountInfos, ToAccountMetas,
+            InitSpace, Key, Lamports, Owner, Owners, ProgramData, Result, Space, StaticSpace,
+            ToAccountInfo, ToAccountInfos, ToAccountMetas,
         },
         crate::solana_program::{
             account_info::{next_account_info, AccountInfo},
@@ -619,6 +656,24 @@ pub mod __private {
         [a, b][(a < b) as usize]
     }
 
+    // Used by `#[derive(InitSpace)]` to warn when `INIT_SPACE` of a `#[repr(C)]` struct is
+    // different from its `size_of`.
+    #[doc(hidden)]
+    pub struct StaticSpaceCheck<const DIVERGES: bool>;
+
+    #[doc(hidden)]
+    impl StaticSpaceCheck<true> {
+        #[deprecated(
+            note = "`INIT_SPACE` is different from `size_of::<Self>()`, the struct likely has padding"
+        )]
+        pub const fn check() {}
+    }
+
+    #[doc(hidden)]
+    impl StaticSpaceCheck<false> {
+        pub const fn check() {}
+    }
+
     // Very experimental trait.
     #[doc(hidden)]
     pub trait ZeroCopyAccessor<Ty> {
@@ -653,6 +708,28 @@ pub mod __private {
     pub trait IsSameType<T> {}
 
     impl<T> IsSameType<T> for T {}
+
+    /// Get the position of the given name in a list of names at compile-time.
+    /// Used to enforce that `#[instruction(...)]` arguments are in the same order as the
+    /// instruction handler arguments.
+    #[doc(hidden)]
+    pub const fn ix_arg_position(names: &[&str], name: &str) -> Option<usize> {
+        let mut i = 0;
+        while i < names.len() {
+            let (a, b) = (names[i].as_bytes(), name.as_bytes());
+            if a.len() == b.len() {
+                let mut j = 0;
+                while j < a.len() && a[j] == b[j] {
+                    j += 1;
+                }
+                if j == a.len() {
+                    return Some(i);
+                }
+            }
+            i += 1;
+        }
+        None
+    }
 }
 
 /// Ensures a condition is true, otherwise returns with the given error.
@@ -1150,6 +1150,28 @@ fn generate_constraint_init_group(
                     seeds_with_bump,
                 );
 
+            // With `realloc`, an existing account is resized to the new space (and funded) by the
+            // realloc constraint, so its current space and rent are not checked here.
+            let (space_check, rent_check) = if f.constraints.realloc.is_some() {
+                (quote! {}, quote! {})
+            } else {
+                (
+                    quote! {
+                        if space != actual_field.data_len() {
+                            return Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintSpace).with_account_name(#name_str).with_values((space, actual_field.data_len())));
+                        }
+                    },
+                    quote! {
+                        {
+                            let required_lamports = __anchor_rent.minimum_balance(space);
+                            if pa.to_account_info().lamports() < required_lamports {
+                                return Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintRentExempt).with_account_name(#name_str));
+                            }
+                        }
+                    },
+                )
+            };
+
             // Put it all together.
             quote! {
                 // Define the bump variable.
@@ -1183,20 +1205,13 @@ fn generate_constraint_init_group(
                     // Assert the account was created correctly.
                     if #if_needed {
                         #owner_optional_check
-                        if space != actual_field.data_len() {
-                            return Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintSpace).with_account_name(#name_str).with_values((space, actual_field.data_len())));
-                        }
+                        #space_check
 
                         if actual_owner != #owner {
                             return Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintOwner).with_account_name(#name_str).with_pubkeys((*actual_owner, *#owner)));
                         }
 
-                        {
-                            let required_lamports = __anchor_rent.minimum_balance(space);
-                            if pa.to_account_info().lamports() < required_lamports {
-                                return Err(anchor_lang::error::Error::from(anchor_lang::error::ErrorCode::ConstraintRentExempt).with_account_name(#name_str));
-                            }
-                        }
+                        #rent_check
                     }
 
                     // Done.
@@ -222,18 +222,30 @@ pub fn generate(accs: &AccountsStruct) -> proc_macro2::TokenStream {
             impl<#combined_generics> #name<#struct_generics> #where_clause {
                 #[doc(hidden)]
                 pub const __ANCHOR_IX_PARAM_COUNT: usize = 0;
+                #[doc(hidden)]
+                pub const __ANCHOR_IX_PARAM_NAMES: &'static [&'static str] = &[];
 
                 #type_validation_methods
             }
         },
         Some(ix_api) => {
             let count = ix_api.len();
+            // Names of the arguments, only used for validation, so unsupported patterns are empty
+            let names = ix_api.iter().map(|arg| match arg {
+                syn::FnArg::Typed(syn::PatType { pat, .. }) => match pat.as_ref() {
+                    syn::Pat::Ident(pat) => pat.ident.to_string(),
+                    _ => String::new(),
+                },
+                syn::FnArg::Receiver(_) => String::new(),
+            });
 
             quote! {
                 #[automatically_derived]
                 impl<#combined_generics> #name<#struct_generics> #where_clause {
                     #[doc(hidden)]
                     pub const __ANCHOR_IX_PARAM_COUNT: usize = #count;
+                    #[doc(hidden)]
+                    pub const __ANCHOR_IX_PARAM_NAMES: &'static [&'static str] = &[#(#names),*];
 
                     #type_validation_methods
                 }
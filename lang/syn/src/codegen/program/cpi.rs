@@ -4,7 +4,8 @@ use {
         Program,
     },
     heck::SnakeCase,
-    quote::{quote, ToTokens},
+    quote::{quote, quote_spanned, ToTokens},
+    syn::spanned::Spanned,
 };
 
 pub fn generate(program: &Program) -> proc_macro2::TokenStream {
@@ -34,6 +35,17 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
                         return quote! { compile_error!(concat!("error generating ix variant name: `", #err, "`")) };
                     }
                 };
+                // Unresolved `impl Trait` return types can't be named outside of the handler
+                if matches!(ix.returns.ty, syn::Type::ImplTrait(_)) {
+                    let err = format!(
+                        "unable to resolve the concrete type of the `impl Trait` return type of \
+                         instruction `{name_str}`. Use a concrete return type instead, e.g. \
+                         `Result<MyStruct>`, or return the value as `Ok(<expr>)` from the \
+                         handler, where `<expr>` is a struct expression, a typed literal, or a \
+                         cast expression."
+                    );
+                    return quote_spanned! { ix.returns.ty.span()=> compile_error!(#err); };
+                }
                 let ret_type = &ix.returns.ty.to_token_stream();
                 let ix_cfgs = &ix.cfgs;
                 let (method_ret, maybe_return) = match ret_type.to_string().as_str() {
@@ -2,7 +2,8 @@ use {crate::Program, heck::CamelCase, quote::quote};
 
 pub fn generate(program: &Program) -> proc_macro2::TokenStream {
     // Dispatch all global instructions.
-    let global_ixs = program.ixs.iter().map(|ix| {
+    let ixs = program.ixs.iter().filter(|ix| !ix.skip_discriminator);
+    let global_ixs = ixs.map(|ix| {
         let ix_method_name = &ix.raw_method.sig.ident;
         #[allow(
             clippy::expect_used,
@@ -46,7 +47,7 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
         }
     };
 
-    let fallback_fn = program
+    let mut fallback_fn = program
         .fallback_fn
         .as_ref()
         .map(|fallback_fn| {
@@ -62,6 +63,29 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
             }
         });
 
+    // An instruction without a discriminator matches any instruction data, so it's called in place
+    // of the fallback function (the parser doesn't allow using both). The instruction's `cfg`s are
+    // taken into account by falling back to the default behavior when they're not satisfied.
+    if let Some(ix) = program.ixs.iter().find(|ix| ix.skip_discriminator) {
+        let ix_method_name = &ix.raw_method.sig.ident;
+        let cfg_preds = ix
+            .cfgs
+            .iter()
+            .map(|attr| attr.parse_args::<proc_macro2::TokenStream>())
+            .collect::<syn::Result<Vec<_>>>();
+        fallback_fn = match cfg_preds {
+            Ok(preds) => quote! {
+                {
+                    #[cfg(all(#(#preds),*))]
+                    return __private::__global::#ix_method_name(program_id, accounts, data);
+                    #[cfg(not(all(#(#preds),*)))]
+                    return #fallback_fn;
+                }
+            },
+            Err(e) => e.into_compile_error(),
+        };
+    }
+
     quote! {
         /// Performs method dispatch.
         ///
@@ -71,8 +95,9 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
         /// If a match is found, the instruction handler is called using the given instruction data
         /// excluding the prepended discriminator bytes.
         ///
-        /// If no match is found, the fallback function is executed if it exists, or an error is
-        /// returned if it doesn't exist.
+        /// If no match is found, the instruction without a discriminator is called with the whole
+        /// instruction data if it exists. Otherwise, the fallback function is executed if it
+        /// exists, or an error is returned if it doesn't exist.
         fn dispatch<'info>(
             program_id: &'info Pubkey,
             accounts: &'info [AccountInfo<'info>],
@@ -46,7 +46,6 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
                 },
             };
 
-
             // Build clear error messages
             let actual_param_count = ix.args.len();
             let count_error_msg = format!(
@@ -78,6 +77,25 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
                 })
                 .collect();
 
+            // Order validation: an arg declared in `#[instruction(...)]` must be at the same
+            // position as in the handler, otherwise it's deserialized from the wrong bytes.
+            let order_validations = ix_arg_names.iter().enumerate().map(|(idx, arg_name)| {
+                let arg_name = arg_name.to_string();
+                let order_error_msg = format!(
+                    "#[instruction(...)] on Account `{}<'_>` declares arg `{}` at a different position than the ix `{}(...)`.",
+                    accounts_struct_name,
+                    arg_name,
+                    ix_method_name_str,
+                );
+                quote! {
+                    if let Some(idx) = anchor_lang::__private::ix_arg_position(EXPECTED_NAMES, #arg_name) {
+                        if idx != #idx {
+                            panic!(#order_error_msg);
+                        }
+                    }
+                }
+            });
+
             let param_validation = quote! {
                 const _: () = {
                     const EXPECTED_COUNT: usize = #accounts_struct_name::__ANCHOR_IX_PARAM_COUNT;
@@ -87,15 +105,26 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
                     if EXPECTED_COUNT > HANDLER_PARAM_COUNT {
                         panic!(#count_error_msg);
                     }
+
+                    // Order validation
+                    const EXPECTED_NAMES: &[&str] = #accounts_struct_name::__ANCHOR_IX_PARAM_NAMES;
+                    #(#order_validations)*
                 };
 
                 // Type validations
                 #(#type_validations)*
             };
 
+            // Deprecated handlers are still dispatched, so the usage shouldn't warn
+            let allow_deprecated = ix
+                .deprecated
+                .as_ref()
+                .map(|_| quote! { #[allow(deprecated)] });
+
             quote! {
                 #(#cfgs)*
                 #[inline(never)]
+                #allow_deprecated
                 pub fn #ix_method_name<'info>(
                     __program_id: &'info Pubkey,
                     __accounts: &'info [AccountInfo<'info>],
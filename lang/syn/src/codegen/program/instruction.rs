@@ -108,6 +108,7 @@ pub fn generate(program: &Program) -> proc_macro2::TokenStream {
 
             let impls = {
                 let discriminator = match ix.overrides.as_ref() {
+                    _ if ix.skip_discriminator => quote! { &[] },
                     Some(overrides) if overrides.discriminator.is_some() => {
                         overrides.discriminator.to_token_stream()
                     }
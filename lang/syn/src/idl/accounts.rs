@@ -318,11 +318,13 @@ fn parse_seed(seed: &syn::Expr, accounts: &AccountsStruct) -> Result<TokenStream
 
             if args.contains_key(&seed_path.name) {
                 let path = seed_path.path();
+                let encoding = get_seed_encoding(seed);
 
                 Ok(quote! {
                     #idl::IdlSeed::Arg(
                         #idl::IdlSeedArg {
                             path: #path.into(),
+                            encoding: #encoding,
                         }
                     )
                 })
@@ -381,6 +383,7 @@ fn parse_seed(seed: &syn::Expr, accounts: &AccountsStruct) -> Result<TokenStream
                         #idl::IdlSeed::Arg(
                             #idl::IdlSeedArg {
                                 path: stringify!(#ident).into(),
+                                encoding: None,
                             }
                         )
                     }
@@ -417,6 +420,21 @@ fn parse_seed(seed: &syn::Expr, accounts: &AccountsStruct) -> Result<TokenStream
     }
 }
 
+/// Get the byte encoding of a seed that converts a number to bytes, e.g. `amount.to_le_bytes()`.
+fn get_seed_encoding(seed: &syn::Expr) -> TokenStream {
+    let idl = get_idl_module_path();
+    let mut expr = seed;
+    while let syn::Expr::MethodCall(call) = expr {
+        match call.method.to_string().as_str() {
+            "to_le_bytes" => return quote! { Some(#idl::IdlSeedEncoding::LeBytes) },
+            "to_be_bytes" => return quote! { Some(#idl::IdlSeedEncoding::BeBytes) },
+            _ => expr = &call.receiver,
+        }
+    }
+
+    quote! { None }
+}
+
 /// SeedPath represents the deconstructed syntax of a single pda seed,
 /// consisting of a variable name and a vec of all the sub fields accessed
 /// on that variable name. For example, if a seed is `my_field.my_data.as_ref()`,
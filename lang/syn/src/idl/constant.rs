@@ -10,19 +10,190 @@ use {
 };
 
 pub fn gen_idl_print_fn_constant(item: &syn::ItemConst) -> TokenStream {
-    let idl = get_idl_module_path();
-    let no_docs = get_no_docs();
-
     let name = item.ident.to_string();
-    let expr = &item.expr;
     let fn_name = format_ident!("__anchor_private_print_idl_const_{}", name.to_snake_case());
+    let expr = &item.expr;
+    gen_idl_print_fn_constant_with_value(&item.attrs, &item.ty, name, fn_name, quote! { #expr })
+}
+
+/// Generate IDL print functions for the public associated constants of an inherent `impl` block
+/// marked with `#[constant]`, with the name `Type::CONST`.
+pub fn gen_idl_print_fn_associated_constants(item: &syn::ItemImpl) -> TokenStream {
+    let print_fns = get_associated_constants(item).map(|(ty_path, ty_ident, item)| {
+        gen_idl_print_fn_associated_constant(ty_ident, item, quote! { #ty_path })
+    });
+    quote! { #(#print_fns)* }
+}
+
+/// Generate IDL print functions for the public constants defined inside the program module.
+///
+/// Constants marked with `#[constant]` are skipped, as they already generate their own print
+/// function. Only constants whose types don't depend on defined types are included.
+///
+/// Public associated constants of inherent `impl` blocks inside the program module (e.g.
+/// `impl super::Pool { pub const FEE: u64 = 30; }`) are also included, with the name
+/// `Type::CONST`.
+pub fn gen_idl_print_fn_program_constants(program_mod: &syn::ItemMod) -> TokenStream {
+    let mod_ident = &program_mod.ident;
+    let Some((_, items)) = &program_mod.content else {
+        return quote! {};
+    };
+
+    let print_fns = items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Const(item) => Some(item),
+            _ => None,
+        })
+        .filter(|item| is_public_const(&item.vis, &item.attrs, &item.ty))
+        .map(|item| {
+            let ident = &item.ident;
+            let fn_name = format_ident!(
+                "__anchor_private_print_idl_program_const_{}",
+                ident.to_string().to_snake_case()
+            );
+            gen_idl_print_fn_constant_with_value(
+                &item.attrs,
+                &item.ty,
+                ident.to_string(),
+                fn_name,
+                quote! { #mod_ident::#ident },
+            )
+        });
+
+    let associated_print_fns = items
+        .iter()
+        .filter_map(|item| match item {
+            // `impl` blocks marked with `#[constant]` generate their own print functions
+            syn::Item::Impl(item) if !is_constant(&item.attrs) => Some(item),
+            _ => None,
+        })
+        .flat_map(get_associated_constants)
+        .map(|(ty_path, ty_ident, item)| {
+            let ty_path = resolve_program_mod_path(mod_ident, items, ty_path);
+            gen_idl_print_fn_associated_constant(ty_ident, item, ty_path)
+        });
+
+    quote! {
+        #(#print_fns)*
+        #(#associated_print_fns)*
+    }
+}
+
+fn is_constant(attrs: &[syn::Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path().is_ident("constant"))
+}
+
+fn is_public_const(vis: &syn::Visibility, attrs: &[syn::Attribute], ty: &syn::Type) -> bool {
+    matches!(vis, syn::Visibility::Public(_))
+        && !is_constant(attrs)
+        && matches!(gen_idl_type(ty, &[]), Ok((_, defined)) if defined.is_empty())
+}
 
-    let docs = match docs::parse(&item.attrs) {
+/// Get the public associated constants of an inherent, non-generic `impl` block, along with the
+/// path and the name of the implemented type.
+fn get_associated_constants(
+    item: &syn::ItemImpl,
+) -> impl Iterator<Item = (&syn::Path, &syn::Ident, &syn::ImplItemConst)> {
+    let ty = match &*item.self_ty {
+        syn::Type::Path(ty)
+            if ty.qself.is_none() && item.trait_.is_none() && item.generics.params.is_empty() =>
+        {
+            ty.path.segments.last().map(|seg| (&ty.path, &seg.ident))
+        }
+        _ => None,
+    };
+
+    ty.into_iter().flat_map(|(ty_path, ty_ident)| {
+        item.items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Const(item) => Some(item),
+                _ => None,
+            })
+            .filter(|item| is_public_const(&item.vis, &item.attrs, &item.ty))
+            .map(move |item| (ty_path, ty_ident, item))
+    })
+}
+
+fn gen_idl_print_fn_associated_constant(
+    ty_ident: &syn::Ident,
+    item: &syn::ImplItemConst,
+    ty_path: TokenStream,
+) -> TokenStream {
+    let ident = &item.ident;
+    let fn_name = format_ident!(
+        "__anchor_private_print_idl_assoc_const_{}_{}",
+        ty_ident.to_string().to_snake_case(),
+        ident.to_string().to_snake_case()
+    );
+    gen_idl_print_fn_constant_with_value(
+        &item.attrs,
+        &item.ty,
+        format!("{ty_ident}::{ident}"),
+        fn_name,
+        quote! { <#ty_path>::#ident },
+    )
+}
+
+/// Resolve a path used inside the program module so that it can be used from the parent module,
+/// where the IDL print functions are generated.
+///
+/// Paths with a single segment are assumed to be imported from the parent module (e.g. via
+/// `use super::*`), unless an item with the same name is defined inside the program module.
+fn resolve_program_mod_path(
+    mod_ident: &syn::Ident,
+    items: &[syn::Item],
+    path: &syn::Path,
+) -> TokenStream {
+    if path.leading_colon.is_some() {
+        return quote! { #path };
+    }
+
+    let segments = path.segments.iter().collect::<Vec<_>>();
+    match segments.as_slice() {
+        [first, rest @ ..] if first.ident == "super" && !rest.is_empty() => {
+            quote! { #(#rest)::* }
+        }
+        [first, rest @ ..] if first.ident == "self" && !rest.is_empty() => {
+            quote! { #mod_ident::#(#rest)::* }
+        }
+        [seg] => {
+            let is_defined_in_mod = items.iter().any(|item| match item {
+                syn::Item::Struct(item) => item.ident == seg.ident,
+                syn::Item::Enum(item) => item.ident == seg.ident,
+                syn::Item::Type(item) => item.ident == seg.ident,
+                _ => false,
+            });
+            if is_defined_in_mod {
+                quote! { #mod_ident::#path }
+            } else {
+                quote! { #path }
+            }
+        }
+        _ => quote! { #path },
+    }
+}
+
+fn gen_idl_print_fn_constant_with_value(
+    attrs: &[syn::Attribute],
+    ty: &syn::Type,
+    name: String,
+    fn_name: syn::Ident,
+    value: TokenStream,
+) -> TokenStream {
+    let idl = get_idl_module_path();
+    let no_docs = get_no_docs();
+
+    let docs = match docs::parse(attrs) {
         Some(docs) if !no_docs => quote! { vec![#(#docs.into()),*] },
         _ => quote! { vec![] },
     };
 
-    let fn_body = match gen_idl_type(&item.ty, &[]) {
+    // Forward `cfg` attributes, as the value doesn't exist when the constant is disabled
+    let cfgs = attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+
+    let fn_body = match gen_idl_type(ty, &[]) {
         Ok((ty, _)) => gen_print_section(
             "const",
             quote! {
@@ -30,7 +201,7 @@ pub fn gen_idl_print_fn_constant(item: &syn::ItemConst) -> TokenStream {
                     name: #name.into(),
                     docs: #docs,
                     ty: #ty,
-                    value: format!("{:?}", #expr),
+                    value: format!("{:?}", #value),
                 }
             },
         ),
@@ -39,6 +210,7 @@ pub fn gen_idl_print_fn_constant(item: &syn::ItemConst) -> TokenStream {
 
     quote! {
         #[test]
+        #(#cfgs)*
         pub fn #fn_name() {
             #fn_body
         }
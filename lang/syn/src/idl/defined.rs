@@ -203,8 +203,9 @@ where
         _ => quote! { vec![] },
     };
 
-    let serialization = get_attr_str("derive", attrs)
-        .and_then(|derive| {
+    let serialization = match get_custom_serialization(attrs)? {
+        Some(custom) => Some(quote! { #idl::IdlSerialization::Custom(#custom.into()) }),
+        None => get_attr_str("derive", attrs).and_then(|derive| {
             if derive.contains("bytemuck") {
                 if derive.to_lowercase().contains("unsafe") {
                     Some(quote! { #idl::IdlSerialization::BytemuckUnsafe })
@@ -214,8 +215,9 @@ where
             } else {
                 None
             }
-        })
-        .unwrap_or_else(|| quote! { #idl::IdlSerialization::default() });
+        }),
+    }
+    .unwrap_or_else(|| quote! { #idl::IdlSerialization::default() });
 
     let repr = get_attr_str("repr", attrs)
         .map(|repr| {
@@ -304,6 +306,43 @@ where
     ))
 }
 
+/// Get the custom serialization from the `#[serialization = "custom:<crate_name>:<version>"]`
+/// attribute, if it exists.
+fn get_custom_serialization(attrs: &[syn::Attribute]) -> Result<Option<String>> {
+    let Some(attr) = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("serialization"))
+    else {
+        return Ok(None);
+    };
+
+    let value = match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(value),
+                    ..
+                }),
+            ..
+        }) => value.value(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Expected `#[serialization = \"custom:<crate_name>:<version>\"]`",
+            ))
+        }
+    };
+
+    if anchor_lang_idl_spec::IdlSerialization::parse_custom(&value).is_none() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "Custom serialization must be in the `custom:<crate_name>:<version>` format",
+        ));
+    }
+
+    Ok(Some(value))
+}
+
 fn get_attr_str(name: impl AsRef<str>, attrs: &[syn::Attribute]) -> Option<String> {
     attrs
         .iter()
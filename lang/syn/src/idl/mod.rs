@@ -13,7 +13,10 @@ mod program;
 pub use {
     accounts::gen_idl_build_impl_accounts_struct,
     address::gen_idl_print_fn_address,
-    constant::gen_idl_print_fn_constant,
+    constant::{
+        gen_idl_print_fn_associated_constants, gen_idl_print_fn_constant,
+        gen_idl_print_fn_program_constants,
+    },
     defined::{impl_idl_build_enum, impl_idl_build_struct, impl_idl_build_union},
     error::gen_idl_print_fn_error,
     event::gen_idl_print_fn_event,
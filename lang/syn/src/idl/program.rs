@@ -1,6 +1,7 @@
 use {
     super::{
         common::{gen_print_section, get_idl_module_path, get_no_docs, get_program_path},
+        constant::gen_idl_print_fn_program_constants,
         defined::gen_idl_type,
     },
     crate::{
@@ -35,6 +36,15 @@ pub fn gen_idl_print_fn_program(program: &Program) -> TokenStream {
             let name_pascal = format_ident!("{}", name.to_camel_case());
             let ctx_ident = &ix.anchor_ident;
             let cfgs = &ix.cfgs;
+            let compute_units = match ix.compute_units {
+                Some(compute_units) => quote! { Some(#compute_units) },
+                None => quote! { None },
+            };
+            let deprecated = ix.deprecated.is_some();
+            let deprecation_note = match ix.deprecated.as_ref().and_then(Option::as_ref) {
+                Some(note) => quote! { Some(#note.into()) },
+                None => quote! { None },
+            };
 
             let docs = match &ix.docs {
                 Some(docs) if !no_docs => quote! { vec![#(#docs.into()),*] },
@@ -73,7 +83,18 @@ pub fn gen_idl_print_fn_program(program: &Program) -> TokenStream {
                     defined.push(def);
                     quote! { Some(#ty) }
                 }
-                _ => quote! { None },
+                _ => {
+                    if matches!(ix.returns.ty, syn::Type::ImplTrait(_)) {
+                        eprintln!(
+                            "Warning: Unable to resolve the concrete type of the `impl Trait` \
+                             return type of instruction `{name}`. Use a concrete return \
+                             type instead, or return the value as `Ok(<expr>)` from the \
+                             handler, where `<expr>` is a struct expression, a typed literal, \
+                             or a cast expression."
+                        );
+                    }
+                    quote! { None }
+                }
             };
 
             Ok((
@@ -89,6 +110,9 @@ pub fn gen_idl_print_fn_program(program: &Program) -> TokenStream {
                         ),
                         args: vec![#(#args),*],
                         returns: #returns,
+                        compute_units: #compute_units,
+                        deprecated: #deprecated,
+                        deprecation_note: #deprecation_note,
                     }
                 },
                 defined,
@@ -143,11 +167,15 @@ pub fn gen_idl_print_fn_program(program: &Program) -> TokenStream {
         },
     );
 
+    let program_constants = gen_idl_print_fn_program_constants(&program.program_mod);
+
     quote! {
         #[test]
         pub fn __anchor_private_print_idl_program() {
             #fn_body
         }
+
+        #program_constants
     }
 }
 
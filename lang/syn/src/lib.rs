@@ -69,6 +69,13 @@ pub struct Ix {
     pub anchor_ident: Ident,
     /// Overrides coming from the `#[instruction]` attribute
     pub overrides: Option<Overrides>,
+    /// Whether the instruction has no discriminator, coming from the
+    /// `#[instruction(skip_discriminator)]` attribute
+    pub skip_discriminator: bool,
+    /// Expected compute units coming from the `#[compute_units]` attribute
+    pub compute_units: Option<u32>,
+    /// Deprecation coming from the `#[deprecated]` attribute, with the optional note
+    pub deprecated: Option<Option<String>>,
 }
 
 /// Common overrides for the `#[instruction]`, `#[account]` and `#[event]` attributes
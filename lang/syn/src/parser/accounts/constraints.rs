@@ -800,7 +800,8 @@ impl<'ty> ConstraintGroupBuilder<'ty> {
                         "space is not required for initializing an spl account",
                     ));
                 }
-                (false, false) => {
+                // `init_if_needed` with `realloc` initializes the account with the realloc space
+                (false, false) if self.realloc.is_none() => {
                     return Err(ParseError::new(
                         i.span(),
                         "space must be provided with init",
@@ -1036,7 +1037,10 @@ impl<'ty> ConstraintGroupBuilder<'ty> {
                             reason = "payer is guaranteed present when init constraint exists"
                         )]
                         payer: into_inner!(payer.clone()).unwrap().target,
-                        space: space.clone().map(|s| s.space.clone()),
+                        space: space
+                            .clone()
+                            .map(|s| s.space.clone())
+                            .or_else(|| realloc.as_ref().map(|r| r.space.clone())),
                         kind: if let Some(tm) = &token_mint {
                             InitKind::Token {
                                 mint: tm.clone().into_inner().mint,
@@ -1314,11 +1318,21 @@ impl<'ty> ConstraintGroupBuilder<'ty> {
                 "realloc must be on an Account, LazyAccount, AccountLoader, or Migration",
             ));
         }
-        if self.mutable.is_none() {
-            return Err(ParseError::new(
-                c.span(),
-                "mut must be provided before realloc",
-            ));
+        match &self.init {
+            Some(init) if !init.if_needed => {
+                return Err(ParseError::new(
+                    c.span(),
+                    "realloc cannot be used with init, use init_if_needed instead",
+                ));
+            }
+            Some(_) => (),
+            None if self.mutable.is_none() => {
+                return Err(ParseError::new(
+                    c.span(),
+                    "mut or init_if_needed must be provided before realloc",
+                ));
+            }
+            None => (),
         }
         if self.realloc.is_some() {
             return Err(ParseError::new(c.span(), "realloc already provided"));
@@ -1816,7 +1830,7 @@ impl<'ty> ConstraintGroupBuilder<'ty> {
 #[cfg(test)]
 mod tests {
     use {
-        crate::{parser::tts_to_string, ConstraintToken},
+        crate::{parser::tts_to_string, AccountTy, ConstraintGroup, ConstraintToken, Ty},
         syn::parse_str,
     };
 
@@ -1855,4 +1869,57 @@ mod tests {
 
         assert_eq!(expression.as_deref(), Some("my_field == 42"));
     }
+
+    fn parse_account_field(constraints: &str) -> syn::Result<ConstraintGroup> {
+        let field = format!("#[account({constraints})] pub data: Account<'info, Data>");
+        let field = syn::parse::Parser::parse_str(syn::Field::parse_named, &field)?;
+        let ty = Ty::Account(AccountTy {
+            account_type_path: syn::parse_quote! { Data },
+            boxed: false,
+        });
+        super::parse(&field, Some(&ty))
+    }
+
+    #[test]
+    fn realloc_requires_mut_or_init_if_needed() {
+        let message =
+            parse_account_field("realloc = 10, realloc::payer = p, realloc::zero = false")
+                .err()
+                .map(|e| e.to_string())
+                .unwrap_or_default();
+        assert_eq!(
+            message,
+            "mut or init_if_needed must be provided before realloc"
+        );
+    }
+
+    #[test]
+    fn realloc_rejects_init() {
+        let message = parse_account_field(
+            "init, payer = p, space = 10, realloc = 10, realloc::payer = p, realloc::zero = false",
+        )
+        .err()
+        .map(|e| e.to_string())
+        .unwrap_or_default();
+        assert_eq!(
+            message,
+            "realloc cannot be used with init, use init_if_needed instead"
+        );
+    }
+
+    #[cfg(feature = "init-if-needed")]
+    #[test]
+    fn init_if_needed_with_realloc_uses_realloc_space() {
+        let group = parse_account_field(
+            "init_if_needed, payer = p, realloc = 10, realloc::payer = p, realloc::zero = false",
+        )
+        .ok();
+        let space = group
+            .as_ref()
+            .and_then(|group| group.init.as_ref())
+            .and_then(|init| init.space.as_ref())
+            .map(tts_to_string);
+        assert_eq!(space.as_deref(), Some("10"));
+        assert!(group.is_some_and(|group| group.realloc.is_some()));
+    }
 }
@@ -3,10 +3,12 @@ use {
         parser::{docs, program::ctx_accounts_ident},
         FallbackFn, Ix, IxArg, IxReturn, Overrides,
     },
+    quote::quote,
     syn::{
         parse::{Error as ParseError, Result as ParseResult},
+        punctuated::Punctuated,
         spanned::Spanned,
-        Attribute,
+        Attribute, Token,
     },
 };
 
@@ -30,7 +32,9 @@ pub fn parse(program_mod: &syn::ItemMod) -> ParseResult<(Vec<Ix>, Option<Fallbac
         })
         .map(|method: &syn::ItemFn| {
             let (ctx, args) = parse_args(method)?;
-            let overrides = parse_overrides(&method.attrs)?;
+            let (overrides, skip_discriminator) = parse_overrides(&method.attrs)?;
+            let compute_units = parse_compute_units(&method.attrs)?;
+            let deprecated = parse_deprecated(&method.attrs)?;
             let docs = docs::parse(&method.attrs);
             let cfgs = parse_cfg(method);
             let returns = parse_return(method)?;
@@ -44,10 +48,21 @@ pub fn parse(program_mod: &syn::ItemMod) -> ParseResult<(Vec<Ix>, Option<Fallbac
                 anchor_ident,
                 returns,
                 overrides,
+                skip_discriminator,
+                compute_units,
+                deprecated,
             })
         })
         .collect::<ParseResult<Vec<Ix>>>()?;
 
+    let mut skip_discriminator_ixs = ixs.iter().filter(|ix| ix.skip_discriminator);
+    if let (Some(_), Some(ix)) = (skip_discriminator_ixs.next(), skip_discriminator_ixs.next()) {
+        return Err(ParseError::new(
+            ix.raw_method.sig.ident.span(),
+            "More than one instruction with `skip_discriminator` found",
+        ));
+    }
+
     let fallback_fn = {
         let fallback_fns = mod_content
             .iter()
@@ -76,21 +91,116 @@ pub fn parse(program_mod: &syn::ItemMod) -> ParseResult<(Vec<Ix>, Option<Fallbac
             })
     };
 
+    if let (Some(ix), Some(fallback_fn)) =
+        (ixs.iter().find(|ix| ix.skip_discriminator), &fallback_fn)
+    {
+        return Err(ParseError::new(
+            fallback_fn.raw_method.sig.ident.span(),
+            format!(
+                "A fallback function cannot be used with `skip_discriminator` instruction `{}`",
+                ix.ident
+            ),
+        ));
+    }
+
     Ok((ixs, fallback_fn))
 }
 
 /// Parse overrides from the `#[instruction]` attribute proc-macro.
-fn parse_overrides(attrs: &[syn::Attribute]) -> ParseResult<Option<Overrides>> {
+///
+/// In addition to the common [`Overrides`], the `skip_discriminator` flag is accepted, which is
+/// only valid for instructions.
+fn parse_overrides(attrs: &[syn::Attribute]) -> ParseResult<(Option<Overrides>, bool)> {
+    let Some(attr) = attrs.iter().find(|attr| match attr.path().segments.last() {
+        Some(seg) => seg.ident == "instruction",
+        _ => false,
+    }) else {
+        return Ok((None, false));
+    };
+
+    let args = attr.parse_args_with(Punctuated::<syn::Meta, Token![,]>::parse_terminated)?;
+    let (flags, args): (Vec<_>, Vec<_>) = args
+        .into_iter()
+        .partition(|arg| arg.path().is_ident("skip_discriminator"));
+    let skip_discriminator = match flags.as_slice() {
+        [] => false,
+        [syn::Meta::Path(_)] => true,
+        [flag] => {
+            return Err(ParseError::new(
+                flag.span(),
+                "`skip_discriminator` doesn't take any value",
+            ))
+        }
+        [_, flag, ..] => {
+            return Err(ParseError::new(
+                flag.span(),
+                "`skip_discriminator` is already provided",
+            ))
+        }
+    };
+
+    let overrides: Overrides = syn::parse2(quote! { #(#args),* })?;
+    if skip_discriminator {
+        if let Some(discriminator) = &overrides.discriminator {
+            return Err(ParseError::new(
+                discriminator.span(),
+                "`discriminator` cannot be used with `skip_discriminator`",
+            ));
+        }
+    }
+
+    Ok((Some(overrides), skip_discriminator))
+}
+
+/// Parse the expected compute units from the `#[compute_units]` attribute proc-macro.
+fn parse_compute_units(attrs: &[syn::Attribute]) -> ParseResult<Option<u32>> {
     attrs
         .iter()
         .find(|attr| match attr.path().segments.last() {
-            Some(seg) => seg.ident == "instruction",
+            Some(seg) => seg.ident == "compute_units",
             _ => false,
         })
-        .map(|attr| attr.parse_args())
+        .map(|attr| attr.parse_args::<syn::LitInt>()?.base10_parse())
         .transpose()
 }
 
+/// Parse the deprecation and its optional note from the standard `#[deprecated]` attribute.
+///
+/// All forms of the attribute are supported:
+///
+/// - `#[deprecated]`
+/// - `#[deprecated = "note"]`
+/// - `#[deprecated(since = "1.0.0", note = "note")]`
+fn parse_deprecated(attrs: &[syn::Attribute]) -> ParseResult<Option<Option<String>>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+
+    let note = match &attr.meta {
+        syn::Meta::Path(_) => None,
+        syn::Meta::NameValue(meta) => match &meta.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(note),
+                ..
+            }) => Some(note.value()),
+            value => return Err(ParseError::new(value.span(), "expected a string literal")),
+        },
+        syn::Meta::List(_) => {
+            let mut note = None;
+            attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<syn::LitStr>()?;
+                if meta.path.is_ident("note") {
+                    note.replace(value.value());
+                }
+                Ok(())
+            })?;
+            note
+        }
+    };
+
+    Ok(Some(note))
+}
+
 pub fn parse_args(method: &syn::ItemFn) -> ParseResult<(IxArg, Vec<IxArg>)> {
     let mut args: Vec<IxArg> = method
         .sig
@@ -147,6 +257,11 @@ pub fn parse_return(method: &syn::ItemFn) -> ParseResult<IxReturn> {
                 _ => &default_generic_arg,
             };
             let ty = match generic_args {
+                // `impl Trait` types can't be used outside of the handler, e.g. in CPI or IDL, so
+                // try to resolve the concrete type from the handler body
+                syn::GenericArgument::Type(ty @ syn::Type::ImplTrait(_)) => {
+                    resolve_impl_trait_return(&method.block).unwrap_or_else(|| ty.clone())
+                }
                 syn::GenericArgument::Type(ty) => ty.clone(),
                 _ => {
                     return Err(ParseError::new(
@@ -164,6 +279,78 @@ pub fn parse_return(method: &syn::ItemFn) -> ParseResult<IxReturn> {
     }
 }
 
+/// Resolve the concrete type of an `impl Trait` return type by inspecting the last expression of
+/// the handler body, which is expected to be in the form of `Ok(<expr>)`.
+///
+/// The type of `<expr>` is resolved when it's one of:
+///
+/// - A struct expression with a single segment path, e.g. `MyStruct { .. }`. Longer paths are not
+///   resolved because they can't be distinguished from enum variants, e.g. `MyEnum::Variant { .. }`
+/// - A literal with a type suffix, e.g. `5u64`
+/// - A cast expression, e.g. `value as u64`
+/// - A local variable that is bound to one of the above, or declared with an explicit type
+///
+/// Returns `None` if the type cannot be resolved.
+fn resolve_impl_trait_return(block: &syn::Block) -> Option<syn::Type> {
+    fn resolve_expr(expr: &syn::Expr, stmts: &[syn::Stmt]) -> Option<syn::Type> {
+        match expr {
+            syn::Expr::Struct(strct) => match strct.path.get_ident() {
+                Some(ident) if strct.qself.is_none() && ident != "Self" => {
+                    Some(syn::Type::Path(syn::TypePath {
+                        qself: None,
+                        path: strct.path.clone(),
+                    }))
+                }
+                _ => None,
+            },
+            syn::Expr::Lit(lit) => match &lit.lit {
+                syn::Lit::Bool(_) => syn::parse_str("bool").ok(),
+                syn::Lit::Int(int) if !int.suffix().is_empty() => syn::parse_str(int.suffix()).ok(),
+                syn::Lit::Float(float) if !float.suffix().is_empty() => {
+                    syn::parse_str(float.suffix()).ok()
+                }
+                _ => None,
+            },
+            syn::Expr::Cast(cast) => Some(*cast.ty.clone()),
+            syn::Expr::Paren(paren) => resolve_expr(&paren.expr, stmts),
+            syn::Expr::Path(path) => {
+                let ident = path.path.get_ident()?;
+                // Only the last binding of the variable is relevant
+                let (idx, local) = stmts.iter().enumerate().rev().find_map(|(idx, stmt)| {
+                    let syn::Stmt::Local(local) = stmt else {
+                        return None;
+                    };
+                    let pat_ident = match &local.pat {
+                        syn::Pat::Ident(pat) => &pat.ident,
+                        syn::Pat::Type(pat) => match pat.pat.as_ref() {
+                            syn::Pat::Ident(pat) => &pat.ident,
+                            _ => return None,
+                        },
+                        _ => return None,
+                    };
+                    (pat_ident == ident).then_some((idx, local))
+                })?;
+                match &local.pat {
+                    syn::Pat::Type(pat) => Some(*pat.ty.clone()),
+                    _ => resolve_expr(&local.init.as_ref()?.expr, stmts.get(..idx)?),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    let (last, stmts) = block.stmts.split_last()?;
+    let syn::Stmt::Expr(syn::Expr::Call(call), None) = last else {
+        return None;
+    };
+    match call.func.as_ref() {
+        syn::Expr::Path(path) if path.path.is_ident("Ok") && call.args.len() == 1 => {
+            resolve_expr(call.args.first()?, stmts)
+        }
+        _ => None,
+    }
+}
+
 fn parse_cfg(method: &syn::ItemFn) -> Vec<Attribute> {
     method
         .attrs
@@ -174,3 +361,110 @@ fn parse_cfg(method: &syn::ItemFn) -> Vec<Attribute> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::{parse_deprecated, parse_overrides, resolve_impl_trait_return},
+        quote::ToTokens,
+        syn::parse_quote,
+    };
+
+    #[test]
+    fn skip_discriminator() {
+        let attrs = vec![parse_quote!(#[instruction(skip_discriminator)])];
+        assert!(matches!(
+            parse_overrides(&attrs),
+            Ok((Some(overrides), true)) if overrides.discriminator.is_none()
+        ));
+    }
+
+    #[test]
+    fn discriminator_without_skip() {
+        let attrs = vec![parse_quote!(#[instruction(discriminator = [1, 2])])];
+        assert!(matches!(
+            parse_overrides(&attrs),
+            Ok((Some(overrides), false)) if overrides.discriminator.is_some()
+        ));
+    }
+
+    #[test]
+    fn skip_discriminator_with_discriminator_errors() {
+        let attrs = vec![parse_quote!(#[instruction(skip_discriminator, discriminator = 1)])];
+        let message = parse_overrides(&attrs).err().map(|e| e.to_string());
+        assert_eq!(
+            message.as_deref(),
+            Some("`discriminator` cannot be used with `skip_discriminator`")
+        );
+    }
+
+    #[test]
+    fn deprecated() {
+        assert_eq!(parse_deprecated(&[]).ok(), Some(None));
+        assert_eq!(
+            parse_deprecated(&[parse_quote!(#[deprecated])]).ok(),
+            Some(Some(None))
+        );
+        assert_eq!(
+            parse_deprecated(&[parse_quote!(#[deprecated = "Use `b`"])]).ok(),
+            Some(Some(Some("Use `b`".into())))
+        );
+        assert_eq!(
+            parse_deprecated(&[parse_quote!(#[deprecated(since = "1.2.0", note = "Use `b`")])])
+                .ok(),
+            Some(Some(Some("Use `b`".into())))
+        );
+    }
+
+    fn resolve(block: syn::Block) -> Option<String> {
+        resolve_impl_trait_return(&block).map(|ty| ty.to_token_stream().to_string())
+    }
+
+    #[test]
+    fn impl_trait_return() {
+        assert_eq!(
+            resolve(parse_quote!({ Ok(MyStruct { value: 1 }) })).as_deref(),
+            Some("MyStruct")
+        );
+        assert_eq!(resolve(parse_quote!({ Ok(5u64) })).as_deref(), Some("u64"));
+        assert_eq!(
+            resolve(parse_quote!({ Ok(x as i32) })).as_deref(),
+            Some("i32")
+        );
+        assert_eq!(
+            resolve(parse_quote!({
+                let s = MyStruct { value: 1 };
+                Ok(s)
+            }))
+            .as_deref(),
+            Some("MyStruct")
+        );
+        assert_eq!(
+            resolve(parse_quote!({
+                let s: Vec<u8> = vec![];
+                Ok(s)
+            }))
+            .as_deref(),
+            Some("Vec < u8 >")
+        );
+    }
+
+    #[test]
+    fn impl_trait_return_unresolved() {
+        assert_eq!(resolve(parse_quote!({ Ok(5) })), None);
+        assert_eq!(resolve(parse_quote!({ Ok(make()) })), None);
+        assert_eq!(resolve(parse_quote!({ Ok(s) })), None);
+        assert_eq!(resolve(parse_quote!({ make() })), None);
+        assert_eq!(
+            resolve(parse_quote!({ Ok(MyEnum::Variant { value: 1 }) })),
+            None
+        );
+        assert_eq!(
+            resolve(parse_quote!({
+                let e = MyEnum::Variant { value: 1 };
+                Ok(e)
+            })),
+            None
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+struct Data {
+    val: u64,
+}
+
+fn serialize_with_header(header: &[u8]) -> Vec<u8> {
+    let mut buf = header.to_vec();
+    assert!(Data { val: 42 }.try_serialize(&mut buf).is_ok());
+    buf
+}
+
+#[test]
+fn try_deserialize_unchecked_with_offset_skips_header() {
+    let buf = serialize_with_header(&[0xff; 4]);
+    let data = Account::<Data>::try_deserialize_unchecked_with_offset(&buf, 4).ok();
+    assert_eq!(data, Some(Data { val: 42 }));
+
+    // Without a header, the offset is 0
+    let buf = serialize_with_header(&[]);
+    let data = Account::<Data>::try_deserialize_unchecked_with_offset(&buf, 0).ok();
+    assert_eq!(data, Some(Data { val: 42 }));
+}
+
+#[test]
+fn try_deserialize_unchecked_with_offset_out_of_bounds() {
+    let buf = serialize_with_header(&[]);
+    for offset in [buf.len() + 1, buf.len(), buf.len() - 1, buf.len() - 7] {
+        let err = Account::<Data>::try_deserialize_unchecked_with_offset(&buf, offset).err();
+        assert_eq!(err, Some(ErrorCode::AccountDiscriminatorNotFound.into()));
+    }
+
+    // Enough bytes for the discriminator but not for the data
+    let err = Account::<Data>::try_deserialize_unchecked_with_offset(&buf, buf.len() - 9).err();
+    assert_eq!(err, Some(ErrorCode::AccountDidNotDeserialize.into()));
+}
@@ -0,0 +1,72 @@
+use anchor_lang::{prelude::*, system_program::Transfer};
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[test]
+fn new_with_signer_and_remaining_adds_remaining_accounts() {
+    let (from_key, to_key, extra_key, other_key) = (
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    );
+    let (mut from_lamports, mut to_lamports, mut extra_lamports, mut other_lamports) = (1, 1, 1, 1);
+    let (mut from_data, mut to_data, mut extra_data, mut other_data) =
+        (vec![], vec![], vec![], vec![]);
+    let owner = crate::ID;
+
+    let from = AccountInfo::new(
+        &from_key,
+        true,
+        true,
+        &mut from_lamports,
+        &mut from_data,
+        &owner,
+        false,
+    );
+    let to = AccountInfo::new(
+        &to_key,
+        false,
+        true,
+        &mut to_lamports,
+        &mut to_data,
+        &owner,
+        false,
+    );
+    let extra = AccountInfo::new(
+        &extra_key,
+        false,
+        false,
+        &mut extra_lamports,
+        &mut extra_data,
+        &owner,
+        false,
+    );
+    let other = AccountInfo::new(
+        &other_key,
+        false,
+        false,
+        &mut other_lamports,
+        &mut other_data,
+        &owner,
+        false,
+    );
+
+    let seeds: &[&[&[u8]]] = &[&[b"seed", &[255]]];
+    let ctx = CpiContext::new_with_signer_and_remaining(
+        System::id(),
+        Transfer { from, to },
+        seeds,
+        vec![extra],
+    )
+    .add_remaining_account(other, true, true);
+
+    assert_eq!(ctx.signer_seeds, seeds);
+    assert_eq!(ctx.remaining_accounts.len(), 2);
+
+    let metas = ctx.to_account_metas(None);
+    assert_eq!(metas.len(), 4);
+    assert_eq!(metas[2], AccountMeta::new_readonly(extra_key, false));
+    assert_eq!(metas[3], AccountMeta::new(other_key, true));
+    assert_eq!(ctx.to_account_infos().len(), 4);
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+const LEGACY_DISCRIMINATOR: &[u8] = &[8, 7, 6, 5, 4, 3, 2, 1];
+
+fn accept_legacy(data: &[u8]) -> bool {
+    data.starts_with(Migrating::DISCRIMINATOR) || data.starts_with(LEGACY_DISCRIMINATOR)
+}
+
+fn accept_legacy_zero_copy(data: &[u8]) -> bool {
+    data.starts_with(MigratingZeroCopy::DISCRIMINATOR) || data.starts_with(LEGACY_DISCRIMINATOR)
+}
+
+#[account(discriminator = [1, 2, 3, 4, 5, 6, 7, 8], runtime_discriminator = accept_legacy)]
+#[derive(Debug)]
+struct Migrating {
+    val: u64,
+}
+
+#[account(zero_copy, runtime_discriminator = self::accept_legacy_zero_copy)]
+#[derive(Debug)]
+struct MigratingZeroCopy {
+    val: u64,
+}
+
+fn with_discriminator(disc: &[u8], val: u64) -> Vec<u8> {
+    let mut data = disc.to_vec();
+    data.extend_from_slice(&val.to_le_bytes());
+    data
+}
+
+#[test]
+fn manual_runtime_discriminator() {
+    struct Current;
+    impl Discriminator for Current {
+        const DISCRIMINATOR: &'static [u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+    }
+
+    // Also accept the discriminator of an older version of the account
+    struct ManualMigrating;
+    impl Discriminator for ManualMigrating {
+        const DISCRIMINATOR: &'static [u8] = &[1, 2, 3, 4, 5, 6, 7, 8];
+
+        fn runtime_discriminator(data: &[u8]) -> bool {
+            data.starts_with(Self::DISCRIMINATOR) || data.starts_with(&[8, 7, 6, 5, 4, 3, 2, 1])
+        }
+    }
+
+    let current = [1, 2, 3, 4, 5, 6, 7, 8, 42];
+    let legacy = [8, 7, 6, 5, 4, 3, 2, 1, 42];
+    assert!(Current::runtime_discriminator(&current));
+    assert!(!Current::runtime_discriminator(&legacy));
+    assert!(!Current::runtime_discriminator(&current[..4]));
+    assert!(ManualMigrating::runtime_discriminator(&current));
+    assert!(ManualMigrating::runtime_discriminator(&legacy));
+}
+
+#[test]
+fn account_accepts_runtime_discriminator() {
+    let data = with_discriminator(Migrating::DISCRIMINATOR, 1);
+    let acc = Migrating::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(acc.val, 1);
+
+    let data = with_discriminator(LEGACY_DISCRIMINATOR, 2);
+    let acc = Migrating::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(acc.val, 2);
+
+    let mut data = with_discriminator(LEGACY_DISCRIMINATOR, 3);
+    let mut lamports = 1;
+    let key = Pubkey::new_unique();
+    let acc_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &ID, false);
+    let acc = Account::<Migrating>::try_from(&acc_info).unwrap();
+    assert_eq!(acc.val, 3);
+
+    let data = with_discriminator(&[0; 8], 4);
+    let err = Migrating::try_deserialize(&mut data.as_slice()).unwrap_err();
+    assert_eq!(err, ErrorCode::AccountDiscriminatorMismatch.into());
+}
+
+#[test]
+fn account_loader_accepts_runtime_discriminator() {
+    let data = with_discriminator(LEGACY_DISCRIMINATOR, 1);
+    let acc = MigratingZeroCopy::try_deserialize(&mut data.as_slice()).unwrap();
+    assert_eq!(acc.val, 1);
+
+    for (disc, val) in [
+        (MigratingZeroCopy::DISCRIMINATOR, 2),
+        (LEGACY_DISCRIMINATOR, 3),
+    ] {
+        let mut data = with_discriminator(disc, val);
+        let mut lamports = 1;
+        let key = Pubkey::new_unique();
+        let acc_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &ID, false);
+        let loader = AccountLoader::<MigratingZeroCopy>::try_from(&acc_info).unwrap();
+        assert_eq!(loader.load().unwrap().val, val);
+    }
+
+    let mut data = with_discriminator(&[0; 8], 4);
+    let mut lamports = 1;
+    let key = Pubkey::new_unique();
+    let acc_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &ID, false);
+    let err = AccountLoader::<MigratingZeroCopy>::try_from(&acc_info).unwrap_err();
+    assert_eq!(err, ErrorCode::AccountDiscriminatorMismatch.into());
+}
@@ -35,6 +35,20 @@ fn test_instruction_data() {
     );
 }
 
+#[test]
+fn test_serialization_attribute_deserialize_only() {
+    #[derive(AnchorDeserialize, Debug, PartialEq, Eq)]
+    #[serialization = "custom:my-serializer:1.0"]
+    struct MyType {
+        foo: u8,
+    }
+
+    assert_eq!(
+        MyType::deserialize(&mut &[7][..]).unwrap(),
+        MyType { foo: 7 }
+    );
+}
+
 #[cfg(not(feature = "lazy-account"))]
 #[test]
 /// Test for <https://github.com/solana-foundation/anchor/issues/4377>;
@@ -421,3 +421,26 @@ fn test_tuple_with_arrays() {
 
     assert!(TestTupleStruct::INIT_SPACE >= array_tuple_size + tuple_array_nested_size);
 }
+
+#[derive(InitSpace)]
+#[repr(C)]
+pub struct TestReprCStruct {
+    pub test_key: Pubkey,
+    pub test_u64: u64,
+    pub test_array: [u32; 4],
+}
+
+#[test]
+fn test_static_space() {
+    fn is_static_space<T: StaticSpace>() -> usize {
+        T::INIT_SPACE
+    }
+
+    assert_eq!(is_static_space::<TestBasicVarAccount>(), 1 + 2 + 4 + 8 + 16);
+    assert_eq!(is_static_space::<TestZeroCopyStruct>(), 8 + 4);
+    assert_eq!(is_static_space::<TestUnitStruct>(), 0);
+    assert_eq!(
+        is_static_space::<TestReprCStruct>(),
+        std::mem::size_of::<TestReprCStruct>()
+    );
+}
@@ -30,6 +30,10 @@ pub mod callee {
         Ok(vec![12, 13, 14, 100])
     }
 
+    pub fn return_impl_trait(_ctx: Context<CpiReturn>) -> Result<impl AnchorSerialize> {
+        Ok(StructReturn { value: 15 })
+    }
+
     // Used for testing views
     pub fn return_u64_from_account(ctx: Context<CpiReturn>) -> Result<u64> {
         let account = &ctx.accounts.account;
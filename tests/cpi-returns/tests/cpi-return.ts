@@ -172,6 +172,22 @@ describe("CPI return", () => {
     assert.deepStrictEqual(returnStructInstruction.returns, {
       defined: { name: "structReturn" },
     });
+
+    // @ts-expect-error
+    const returnImplTraitInstruction = calleeProgram._idl.instructions.find(
+      (f) => f.name == "returnImplTrait"
+    );
+    assert.deepStrictEqual(returnImplTraitInstruction.returns, {
+      defined: { name: "structReturn" },
+    });
+  });
+
+  it("can return an `impl Trait` type via view", async () => {
+    const struct = await calleeProgram.methods
+      .returnImplTrait()
+      .accounts({ account: cpiReturn.publicKey })
+      .view();
+    assert(struct.value.eq(new anchor.BN(15)));
   });
 
   it("can return a u64 via view", async () => {
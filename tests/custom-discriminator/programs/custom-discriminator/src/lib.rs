@@ -40,6 +40,12 @@ pub mod custom_discriminator {
         Ok(())
     }
 
+    #[instruction(skip_discriminator)]
+    pub fn skip(_ctx: Context<DefaultIx>, data: [u8; 8]) -> Result<()> {
+        msg!("Skipped discriminator: {:?}", data);
+        Ok(())
+    }
+
     pub fn account(ctx: Context<CustomAccountIx>, field: u8) -> Result<()> {
         ctx.accounts.my_account.field = field;
         Ok(())
@@ -30,6 +30,22 @@ describe("custom-discriminator", () => {
     it("Byte string", () => testCommon("byteStr"));
     it("Constant", () => testCommon("constant"));
     it("Const Fn", () => testCommon("constFn"));
+
+    it("Skip discriminator", async () => {
+      const ix = program.idl.instructions.find((ix) => ix.name === "skip")!;
+      assert.strictEqual(ix.discriminator.length, 0);
+
+      // Any data that doesn't match the other instructions' discriminators is
+      // accepted and deserialized from the first byte
+      const data = [200, 201, 202, 203, 204, 205, 206, 207];
+      const tx = await program.methods.skip(data).transaction();
+      assert(tx.instructions[0].data.equals(Buffer.from(data)));
+
+      await program.provider.sendAndConfirm!(tx, [], {
+        maxRetries: 3,
+        skipPreflight: true,
+      });
+    });
   });
 
   describe("Accounts", () => {
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 
 declare_program!(external);
+declare_program!(skip_discriminator);
 
 #[test]
 pub fn test_account_parser() {
@@ -304,6 +305,39 @@ pub fn test_instruction_parser() {
     };
 }
 
+#[test]
+pub fn test_instruction_parser_skip_discriminator() {
+    use {
+        anchor_lang::solana_program::instruction::Instruction as SolanaInstruction,
+        skip_discriminator::parsers::Instruction,
+    };
+
+    // The instruction without a discriminator (`any`) sorts first, but it must be checked last
+    let parse = |data: &[u8]| {
+        Instruction::parse(&SolanaInstruction::new_with_bytes(
+            skip_discriminator::ID,
+            data,
+            vec![],
+        ))
+    };
+
+    match parse(&[1]) {
+        Ok(Instruction::Init { .. }) => {}
+        Ok(_) => panic!("Expected Init instruction variant"),
+        Err(e) => panic!("Expected Ok result, got error: {:?}", e),
+    };
+    match parse(&[2, 42, 0, 0, 0]) {
+        Ok(Instruction::Update { args, .. }) => assert_eq!(args.value, 42),
+        Ok(_) => panic!("Expected Update instruction variant"),
+        Err(e) => panic!("Expected Ok result, got error: {:?}", e),
+    };
+    match parse(&7u64.to_le_bytes()) {
+        Ok(Instruction::Any { args, .. }) => assert_eq!(args.value, 7),
+        Ok(_) => panic!("Expected Any instruction variant"),
+        Err(e) => panic!("Expected Ok result, got error: {:?}", e),
+    };
+}
+
 #[test]
 #[cfg(not(feature = "idl-build"))]
 pub fn test_errors() {
@@ -21,16 +21,39 @@ pub const NO_IDL: u16 = 55;
 pub mod idl {
     use super::*;
 
+    /// Public constants of the program module are included in the IDL.
+    pub const PROGRAM_U64: u64 = 10_000;
+    pub const PROGRAM_I32: i32 = -5;
+    pub const PROGRAM_BYTES: [u8; 32] = [1; 32];
+    pub const PROGRAM_PUBKEY: Pubkey = pubkey!("id11111111111111111111111111111111111111111");
+    #[cfg(not(feature = "idl-build"))]
+    pub const PROGRAM_CFG_DISABLED: u8 = 1;
+
+    /// Public associated constants of `impl` blocks inside the program module are included in
+    /// the IDL.
+    impl SimpleAccount {
+        pub const MAX_FIELD: u8 = u8::MAX;
+    }
+
+    // Doesn't conflict with the print function of `SimpleAccount::MAX_FIELD`
+    pub const SIMPLE_ACCOUNT_MAX_FIELD: u8 = u8::MAX;
+
     pub fn no_case_conversion(ctx: Context<NoCaseConversion>, field_name: u8) -> Result<()> {
         ctx.accounts.case_conversion_account.field_name = field_name;
         emit!(SimpleEvent { field_name });
         Ok(())
     }
 
+    #[compute_units(5_000)]
     pub fn empty(_ctx: Context<Empty>) -> Result<()> {
         Ok(())
     }
 
+    #[deprecated(since = "0.2.0", note = "Use `empty` instead")]
+    pub fn deprecated_empty(_ctx: Context<Empty>) -> Result<()> {
+        Ok(())
+    }
+
     pub fn primitive_types(
         ctx: Context<PrimitiveTypes>,
         bool: bool,
@@ -186,6 +209,10 @@ pub mod idl {
     pub fn test_compilation(_ctx: Context<TestCompilation>) -> Result<()> {
         Ok(())
     }
+
+    pub fn custom_serialization(_ctx: Context<Empty>, _arg: CustomSerialized) -> Result<()> {
+        Ok(())
+    }
 }
 
 /// IDL test for the issue explained in https://github.com/solana-foundation/anchor/issues/3358
@@ -203,6 +230,12 @@ pub struct SimpleAccount {
     pub field_name: u8,
 }
 
+/// Public associated constants of `impl` blocks marked with `#[constant]` are included in the IDL.
+#[constant]
+impl SimpleAccount {
+    pub const FEE_DENOMINATOR: u64 = 10_000;
+}
+
 #[event]
 #[derive(Clone)]
 pub struct SimpleEvent {
@@ -283,6 +316,12 @@ pub struct NamedStruct {
 #[derive(AnchorDeserialize, AnchorSerialize, Clone)]
 pub struct TupleStruct(u64, String);
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[serialization = "custom:my-serializer:1.0"]
+pub struct CustomSerialized {
+    pub value: u64,
+}
+
 #[derive(Accounts)]
 pub struct Enum<'info> {
     #[account(zero)]
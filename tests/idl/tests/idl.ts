@@ -567,6 +567,39 @@ describe("IDL", () => {
       assert.deepEqual(zcUnsafeAccount.repr, { kind: "rust", packed: true });
     });
 
+    it("Includes compute units marked with `#[compute_units]`", () => {
+      const ix = program.rawIdl.instructions.find((ix) => ix.name === "empty");
+      if (!ix) throw new Error("Instruction `empty` not found");
+
+      assert.strictEqual(ix.compute_units, 5000);
+    });
+
+    it("Includes deprecation info of `#[deprecated]` instructions", () => {
+      const ix = program.rawIdl.instructions.find(
+        (ix) => ix.name === "deprecated_empty"
+      );
+      if (!ix) throw new Error("Instruction `deprecated_empty` not found");
+
+      assert.isTrue(ix.deprecated);
+      assert.strictEqual(ix.deprecation_note, "Use `empty` instead");
+
+      const empty = program.rawIdl.instructions.find(
+        (ix) => ix.name === "empty"
+      );
+      assert.isUndefined(empty?.deprecated);
+    });
+
+    it("Includes custom serialization marked with `#[serialization]`", () => {
+      const ty = program.rawIdl.types?.find(
+        (ty) => ty.name === "CustomSerialized"
+      );
+      if (!ty) throw new Error("Type `CustomSerialized` not found");
+
+      assert.deepEqual(ty.serialization, {
+        custom: "custom:my-serializer:1.0",
+      });
+    });
+
     it("Does not include external accounts", () => {
       const external: anchor.Program<External> = anchor.workspace.external;
       assert.isDefined(
@@ -608,6 +641,42 @@ describe("IDL", () => {
       );
     });
 
+    it("Includes public constants of the program module", () => {
+      const findConstant = (name: string) => {
+        const constant = program.rawIdl.constants?.find((c) => c.name === name);
+        if (!constant) throw new Error(`Constant \`${name}\` not found`);
+        return constant;
+      };
+
+      assert.deepEqual(findConstant("PROGRAM_U64").value, "10000");
+      assert.deepEqual(findConstant("PROGRAM_I32").value, "-5");
+      assert.deepEqual(findConstant("PROGRAM_BYTES").type, { array: ["u8", 32] });
+      assert.deepEqual(
+        findConstant("PROGRAM_PUBKEY").value,
+        "id11111111111111111111111111111111111111111"
+      );
+      assert.isUndefined(
+        program.rawIdl.constants?.find(
+          (c) => c.name === "PROGRAM_CFG_DISABLED"
+        )
+      );
+    });
+
+    it("Includes public associated constants of account types", () => {
+      const findConstant = (name: string) => {
+        const constant = program.rawIdl.constants?.find((c) => c.name === name);
+        if (!constant) throw new Error(`Constant \`${name}\` not found`);
+        return constant;
+      };
+
+      assert.deepEqual(
+        findConstant("SimpleAccount::FEE_DENOMINATOR").value,
+        "10000"
+      );
+      assert.deepEqual(findConstant("SimpleAccount::MAX_FIELD").type, "u8");
+      assert.deepEqual(findConstant("SimpleAccount::MAX_FIELD").value, "255");
+    });
+
     it("Does not include constants that are not marked with `#[constant]`", () => {
       // @ts-expect-error
       assert.isUndefined(program.idl.constants.find((c) => c.name === "noIdl"));
@@ -135,6 +135,17 @@ describe("typescript", () => {
     }
   });
 
+  it("Includes the encoding of number argument seeds", async () => {
+    const acc = program.idl.instructions
+      .find((ix) => ix.name === "initMyAccount")!
+      .accounts.find((acc) => acc.name === "account")!;
+    expect(acc.pda.seeds[0]).to.deep.equal({
+      kind: "arg",
+      path: "seedA",
+      encoding: "le_bytes",
+    });
+  });
+
   it("Skips resolution if `program::seeds` expression is not supported", async () => {
     const acc = program.idl.instructions
       .find((ix) => ix.name === "unsupportedProgramSeed")!
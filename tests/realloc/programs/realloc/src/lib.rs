@@ -32,6 +32,12 @@ pub mod realloc {
             .resize_with(len as usize, Default::default);
         Ok(())
     }
+
+    pub fn init_if_needed_realloc(ctx: Context<InitIfNeededRealloc>, len: u16) -> Result<()> {
+        ctx.accounts.sample.bump = ctx.bumps.sample;
+        ctx.accounts.sample.data.resize(len as usize, 1);
+        Ok(())
+    }
 }
 
 #[derive(Accounts)]
@@ -100,6 +106,26 @@ pub struct Realloc2<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(len: u16)]
+pub struct InitIfNeededRealloc<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        seeds = [b"lazy"],
+        bump,
+        realloc = Sample::space(len as usize),
+        realloc::payer = authority,
+        realloc::zero = false,
+    )]
+    pub sample: Account<'info, Sample>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[account]
 pub struct Sample {
     pub data: Vec<u8>,
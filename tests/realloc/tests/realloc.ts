@@ -68,6 +68,32 @@ describe("realloc", () => {
     assert.lengthOf(s.data, 1);
   });
 
+  it("init_if_needed with realloc initializes and reallocates", async () => {
+    const [lazy] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("lazy")],
+      program.programId
+    );
+
+    await program.methods
+      .initIfNeededRealloc(2)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+
+    let s = await program.account.sample.fetch(lazy);
+    assert.deepEqual([...s.data], [1, 1]);
+
+    await program.methods
+      .initIfNeededRealloc(4)
+      .accounts({ authority: authority.publicKey })
+      .rpc();
+
+    s = await program.account.sample.fetch(lazy);
+    assert.deepEqual([...s.data], [1, 1, 1, 1]);
+
+    const info = await program.provider.connection.getAccountInfo(lazy);
+    assert.strictEqual(info.data.length, 8 + (4 + 4) + 1);
+  });
+
   it("fails with duplicate account reallocations", async () => {
     try {
       await program.methods
@@ -0,0 +1,27 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[program]
+pub mod test_instruction_validation {
+    use super::*;
+
+    pub fn has_one_missing_field(_ctx: Context<HasOneMissingField>) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct HasOneMissingField<'info> {
+    // `Data` doesn't have an `owner` field
+    #[account(has_one = owner)]
+    pub data: Account<'info, Data>,
+    pub owner: Signer<'info>,
+}
+
+#[account]
+pub struct Data {
+    pub authority: Pubkey,
+}
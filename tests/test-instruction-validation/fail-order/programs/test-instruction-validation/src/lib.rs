@@ -0,0 +1,23 @@
+#![allow(unexpected_cfgs)]
+
+use anchor_lang::prelude::*;
+
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+#[program]
+pub mod test_instruction_validation {
+    use super::*;
+
+    // Order mismatch - handler has `(a, b)` but #[instruction(...)] has `(b, a)`
+    pub fn order_mismatch(_ctx: Context<OrderMismatch>, a: u64, b: u64) -> Result<()> {
+        msg!("a: {}, b: {}", a, b);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(b: u64, a: u64)] // Same types as the handler, but in a different order
+pub struct OrderMismatch<'info> {
+    #[account(seeds = [&a.to_le_bytes(), &b.to_le_bytes()], bump)]
+    pub pda: SystemAccount<'info>,
+}
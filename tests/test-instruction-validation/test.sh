@@ -42,4 +42,26 @@ else
     echo "FAIL: Expected successful compilation but build failed"
     exit 1
 fi
-cd ..
\ No newline at end of file
+cd ..
+
+echo "Test 5: Running FAIL-ORDER case (expects compilation error)..."
+cd fail-order
+if cargo build 2>&1 | grep -q "at a different position"; then
+    echo "PASS: FAIL-ORDER case correctly caught argument order mismatch at compile time"
+else
+    echo "FAIL: Expected compilation error but build succeeded or wrong error"
+    echo "Build output: $BUILD_OUTPUT"
+    exit 1
+fi
+cd ..
+
+echo "Test 6: Running FAIL-HAS-ONE case (expects compilation error)..."
+cd fail-has-one
+if cargo build 2>&1 | grep -q "no field \`owner\`"; then
+    echo "PASS: FAIL-HAS-ONE case correctly caught missing has_one field at compile time"
+else
+    echo "FAIL: Expected compilation error but build succeeded or wrong error"
+    echo "Build output: $BUILD_OUTPUT"
+    exit 1
+fi
+cd ..
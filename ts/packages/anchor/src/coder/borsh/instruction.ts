@@ -29,7 +29,14 @@ export class BorshInstructionCoder implements InstructionCoder {
   >;
 
   public constructor(private idl: Idl) {
-    const ixLayouts = idl.instructions.map((ix) => {
+    // Instructions without a discriminator match any data, so they're checked
+    // last when decoding (same as the on-chain dispatch)
+    const instructions = [...idl.instructions].sort(
+      (a, b) =>
+        Number(a.discriminator.length === 0) -
+        Number(b.discriminator.length === 0)
+    );
+    const ixLayouts = instructions.map((ix) => {
       const name = ix.name;
       const fieldLayouts = ix.args.map((arg) =>
         IdlCoder.fieldLayout(arg, idl.types)
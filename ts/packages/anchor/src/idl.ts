@@ -46,6 +46,9 @@ export type IdlInstruction = {
   accounts: IdlInstructionAccountItem[];
   args: IdlField[];
   returns?: IdlType;
+  compute_units?: number;
+  deprecated?: boolean;
+  deprecation_note?: string;
 };
 
 export type IdlInstructionAccountItem =
@@ -83,8 +86,11 @@ export type IdlSeedConst = {
 export type IdlSeedArg = {
   kind: "arg";
   path: string;
+  encoding?: IdlSeedEncoding;
 };
 
+export type IdlSeedEncoding = "le_bytes" | "be_bytes";
+
 export type IdlSeedAccount = {
   kind: "account";
   path: string;
@@ -407,7 +407,9 @@ export class AccountsResolver<IDL extends Idl> {
     }
 
     const type = this.getType(this._idlIx.args[index].type, path);
-    return this.toBufferValue(type, value);
+    const buf = this.toBufferValue(type, value);
+    // Numbers are little-endian by default
+    return seed.encoding === "be_bytes" ? buf.reverse() : buf;
   }
 
   private async toBufferAccount(